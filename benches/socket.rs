@@ -0,0 +1,122 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use tokio::{net::UdpSocket, runtime::Runtime, sync::RwLock, task::JoinHandle};
+use udp::*;
+
+const TASKS: usize = 8;
+const DATAGRAMS_PER_TASK: usize = 64;
+const PAYLOAD: &[u8] = &[0u8; 64];
+
+#[derive(Clone, Copy, Debug)]
+struct EchoHandler;
+
+impl ServerHook for EchoHandler {
+    async fn new(_ctx: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let request: Request = ctx.get_request().await;
+        let _: Result<(), ResponseError> = ctx.send(request).await;
+    }
+}
+
+fn runtime() -> Runtime {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+}
+
+async fn bind_pair() -> (UdpSocket, SocketAddr) {
+    let sink: UdpSocket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let target: SocketAddr = sink.local_addr().unwrap();
+    tokio::spawn(async move {
+        let mut buf: [u8; 1024] = [0u8; 1024];
+        while sink.recv_from(&mut buf).await.is_ok() {}
+    });
+    (UdpSocket::bind("127.0.0.1:0").await.unwrap(), target)
+}
+
+async fn send_rwlock(socket: Arc<RwLock<UdpSocket>>, target: SocketAddr) {
+    let tasks: Vec<JoinHandle<()>> = (0..TASKS)
+        .map(|_| {
+            let socket: Arc<RwLock<UdpSocket>> = socket.clone();
+            tokio::spawn(async move {
+                for _ in 0..DATAGRAMS_PER_TASK {
+                    let _: std::io::Result<usize> =
+                        socket.read().await.send_to(PAYLOAD, target).await;
+                }
+            })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap();
+    }
+}
+
+async fn send_arc(socket: Arc<UdpSocket>, target: SocketAddr) {
+    let tasks: Vec<JoinHandle<()>> = (0..TASKS)
+        .map(|_| {
+            let socket: Arc<UdpSocket> = socket.clone();
+            tokio::spawn(async move {
+                for _ in 0..DATAGRAMS_PER_TASK {
+                    let _: std::io::Result<usize> = socket.send_to(PAYLOAD, target).await;
+                }
+            })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap();
+    }
+}
+
+fn bench_send(c: &mut Criterion) {
+    let runtime: Runtime = runtime();
+    let (socket, target) = runtime.block_on(bind_pair());
+    let arc_socket: Arc<UdpSocket> = Arc::new(socket);
+    let (socket, rwlock_target) = runtime.block_on(bind_pair());
+    let rwlock_socket: Arc<RwLock<UdpSocket>> = Arc::new(RwLock::new(socket));
+    let mut group = c.benchmark_group("concurrent_send");
+    group.throughput(Throughput::Elements((TASKS * DATAGRAMS_PER_TASK) as u64));
+    group.bench_function("rwlock", |b| {
+        b.to_async(&runtime)
+            .iter(|| send_rwlock(rwlock_socket.clone(), rwlock_target));
+    });
+    group.bench_function("arc", |b| {
+        b.to_async(&runtime)
+            .iter(|| send_arc(arc_socket.clone(), target));
+    });
+    group.finish();
+}
+
+fn bench_server_echo(c: &mut Criterion) {
+    let runtime: Runtime = runtime();
+    let (server_control_hook, client, local_addr) = runtime.block_on(async {
+        let server_config: ServerConfig = ServerConfig::new().await;
+        server_config.host("127.0.0.1").await;
+        server_config.port(0).await;
+        let server: Server = Server::new().await;
+        server.server_config(server_config).await;
+        server.hook::<EchoHandler>().await;
+        let server_control_hook: ServerControlHook = server.run().await.unwrap();
+        let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+        let client: UdpSocket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        (server_control_hook, client, local_addr)
+    });
+    let mut group = c.benchmark_group("server_echo");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("round_trip", |b| {
+        b.to_async(&runtime).iter(|| async {
+            let mut buf: [u8; 1024] = [0u8; 1024];
+            client.send_to(PAYLOAD, local_addr).await.unwrap();
+            client.recv_from(&mut buf).await.unwrap();
+        });
+    });
+    group.finish();
+    runtime.block_on(server_control_hook.shutdown());
+}
+
+criterion_group!(benches, bench_send, bench_server_echo);
+criterion_main!(benches);
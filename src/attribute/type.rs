@@ -6,6 +6,15 @@ use super::*;
 /// different parts of the application in a thread-safe manner.
 pub type ThreadSafeAttributeStore = HashMap<String, Arc<dyn Any + Send + Sync>>;
 
+/// Type alias for typed shared state storage.
+///
+/// Each value is keyed by its `TypeId`, so at most one value of a given type
+/// can be registered.
+pub type ThreadSafeStateStore = HashMap<TypeId, Arc<dyn Any + Send + Sync>>;
+
+/// Type alias for a shared state store handed to every context.
+pub type SharedStateStore = Arc<ThreadSafeStateStore>;
+
 /// Type alias for types that implement Any + Send + Sync + Clone.
 ///
 /// This is a trait alias for types that can be stored in the attribute store
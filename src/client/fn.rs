@@ -0,0 +1,98 @@
+use super::*;
+
+/// The delay before receiving again after the first of consecutive receive errors.
+const MIN_RECEIVE_ERROR_BACKOFF: Duration = Duration::from_millis(10);
+
+/// The longest delay before receiving again after consecutive receive errors.
+const MAX_RECEIVE_ERROR_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Locks the pending reply map, recovering it if a previous holder panicked.
+///
+/// # Arguments
+///
+/// - `&PendingReplies` - The pending reply map.
+///
+/// # Returns
+///
+/// - `MutexGuard<HashMap<u32, PendingReply>>` - The guard for the map.
+pub(crate) fn lock_pending(pending: &PendingReplies) -> MutexGuard<'_, HashMap<u32, PendingReply>> {
+    pending.lock().unwrap_or_else(
+        |error: PoisonError<MutexGuard<'_, HashMap<u32, PendingReply>>>| error.into_inner(),
+    )
+}
+
+/// Fails the requests waiting for a reply from the given peer with a receive error.
+///
+/// # Arguments
+///
+/// - `&PendingReplies` - The replies awaited by in-flight requests.
+/// - `SocketAddr` - The peer the error was reported for.
+/// - `&std::io::Error` - The receive error.
+fn fail_pending(pending: &PendingReplies, peer: SocketAddr, error: &std::io::Error) {
+    let failed: Vec<PendingReply> = {
+        let mut replies: MutexGuard<'_, HashMap<u32, PendingReply>> = lock_pending(pending);
+        let ids: Vec<u32> = replies
+            .iter()
+            .filter(|(_, reply): &(&u32, &PendingReply)| reply.addr == peer)
+            .map(|(id, _): (&u32, &PendingReply)| *id)
+            .collect();
+        ids.iter()
+            .filter_map(|id: &u32| replies.remove(id))
+            .collect()
+    };
+    for reply in failed {
+        let _: Result<(), ReplyResult> = reply
+            .sender
+            .send(Err(ResponseError::ReceiveError(error.to_string())));
+    }
+}
+
+/// Receives framed replies and hands each one to the request waiting for its ID.
+///
+/// Replies with an unknown ID, or from an address other than the one the request
+/// was sent to, are discarded. A receive error on a connected socket can only
+/// concern the connected peer, so it fails the requests waiting for that peer. On
+/// an unconnected socket the error cannot be attributed to a peer, so waiting
+/// requests are left to their own timeout and retries. Either way the next receive
+/// is delayed, doubling up to `MAX_RECEIVE_ERROR_BACKOFF` while errors keep
+/// coming, so a persistent error does not spin.
+///
+/// # Arguments
+///
+/// - `ArcUdpSocket` - The client socket.
+/// - `PendingReplies` - The replies awaited by in-flight requests.
+/// - `usize` - The receive buffer size.
+pub(crate) async fn demultiplex_replies(
+    socket: ArcUdpSocket,
+    pending: PendingReplies,
+    buffer_size: usize,
+) {
+    let mut pool: BufferPool = BufferPool::new(buffer_size);
+    let mut backoff: Duration = MIN_RECEIVE_ERROR_BACKOFF;
+    loop {
+        let mut buf: &mut [MaybeUninit<u8>] = pool.buffer();
+        let (len, from): (usize, SocketAddr) = match socket.recv_buf_from(&mut buf).await {
+            Ok(received) => received,
+            Err(error) => {
+                if let Ok(peer) = socket.peer_addr() {
+                    fail_pending(&pending, peer, &error);
+                }
+                sleep(backoff).await;
+                backoff = backoff.saturating_mul(2).min(MAX_RECEIVE_ERROR_BACKOFF);
+                continue;
+            }
+        };
+        backoff = MIN_RECEIVE_ERROR_BACKOFF;
+        let Some((id, payload)) = split_correlation_id(&pool.take(len)) else {
+            continue;
+        };
+        let mut replies: MutexGuard<'_, HashMap<u32, PendingReply>> = lock_pending(&pending);
+        if replies
+            .get(&id)
+            .is_some_and(|reply: &PendingReply| reply.addr == from)
+            && let Some(reply) = replies.remove(&id)
+        {
+            let _: Result<(), ReplyResult> = reply.sender.send(Ok(payload));
+        }
+    }
+}
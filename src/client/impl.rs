@@ -0,0 +1,322 @@
+use super::*;
+
+/// Implementation block for `Client`.
+impl Client {
+    /// Creates a client and binds its socket to the configured local address.
+    ///
+    /// With correlation ID framing enabled, a background task is started that
+    /// routes replies to waiting requests; it stops when the last clone of the
+    /// client is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// - `ClientConfig` - The client configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Client, ResponseError>` - The client, or `ResponseError::BindError` on failure.
+    pub async fn new(config: ClientConfig) -> Result<Self, ResponseError> {
+        let config: ClientConfigData = config.get_data().await;
+        let bind: &String = config.get_bind();
+        let socket: UdpSocket =
+            UdpSocket::bind(bind.as_str())
+                .await
+                .map_err(|error: std::io::Error| {
+                    ResponseError::BindError(format!("{bind}: {error}"))
+                })?;
+        let socket: ArcUdpSocket = Arc::new(socket);
+        let correlation: Option<ClientCorrelation> = config.get_correlation_ids().then(|| {
+            let pending: PendingReplies = Arc::new(Mutex::new(HashMap::new()));
+            let receiver: JoinHandle<()> = tokio::spawn(demultiplex_replies(
+                socket.clone(),
+                pending.clone(),
+                config.get_buffer_size(),
+            ));
+            ClientCorrelation {
+                pending,
+                next_id: AtomicU32::new(0),
+                _receiver: AbortOnDrop::new(&receiver),
+            }
+        });
+        let recv_pool: SharedBufferPool =
+            Arc::new(AsyncMutex::new(BufferPool::new(config.get_buffer_size())));
+        Ok(Self(arc_rwlock(ClientData {
+            socket,
+            config,
+            peer: None,
+            recv_pool,
+            correlation,
+        })))
+    }
+
+    /// Acquires a read lock on the client state.
+    ///
+    /// # Returns
+    ///
+    /// - `RwLockReadGuard<ClientData>` - The read guard.
+    async fn read(&self) -> RwLockReadGuard<'_, ClientData> {
+        self.0.read().await
+    }
+
+    /// Acquires a write lock on the client state.
+    ///
+    /// # Returns
+    ///
+    /// - `RwLockWriteGuard<ClientData>` - The write guard.
+    async fn write(&self) -> RwLockWriteGuard<'_, ClientData> {
+        self.0.write().await
+    }
+
+    /// Gets the client socket.
+    ///
+    /// # Returns
+    ///
+    /// - `ArcUdpSocket` - The shared client socket.
+    pub async fn get_socket(&self) -> ArcUdpSocket {
+        self.read().await.socket.clone()
+    }
+
+    /// Gets the local address of the client socket.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SocketAddr>` - The local address, if it can be determined.
+    pub async fn get_local_addr(&self) -> Option<SocketAddr> {
+        self.read().await.socket.local_addr().ok()
+    }
+
+    /// Gets the peer the client is connected to.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SocketAddr>` - The connected peer, if any.
+    pub async fn get_peer_addr(&self) -> Option<SocketAddr> {
+        self.read().await.peer
+    }
+
+    /// Connects the client to a fixed peer.
+    ///
+    /// Afterwards `send` and `request` talk to this peer, and the kernel drops
+    /// datagrams from any other address.
+    ///
+    /// # Arguments
+    ///
+    /// - `SocketAddr` - The peer address.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the connect operation.
+    pub async fn connect(&self, addr: SocketAddr) -> ResponseResult {
+        let mut client: RwLockWriteGuard<'_, ClientData> = self.write().await;
+        client
+            .socket
+            .connect(addr)
+            .await
+            .map_err(|error: std::io::Error| ResponseError::SendError(error.to_string()))?;
+        client.peer = Some(addr);
+        Ok(())
+    }
+
+    /// Sends a datagram to the connected peer.
+    ///
+    /// # Arguments
+    ///
+    /// - `Into<ResponseData>` - The datagram payload.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the send operation, or
+    ///   `ResponseError::AddressNotAvailable` when the client is not connected.
+    pub async fn send<T>(&self, data: T) -> ResponseResult
+    where
+        T: Into<ResponseData>,
+    {
+        let peer: SocketAddr = self
+            .get_peer_addr()
+            .await
+            .ok_or(ResponseError::AddressNotAvailable)?;
+        self.send_to(data, peer).await
+    }
+
+    /// Sends a datagram to the given address.
+    ///
+    /// With correlation ID framing enabled, the datagram carries a fresh ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `Into<ResponseData>` - The datagram payload.
+    /// - `SocketAddr` - The destination address.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the send operation.
+    pub async fn send_to<T>(&self, data: T, addr: SocketAddr) -> ResponseResult
+    where
+        T: Into<ResponseData>,
+    {
+        let (socket, correlation_id): (ArcUdpSocket, Option<u32>) = {
+            let client: RwLockReadGuard<'_, ClientData> = self.read().await;
+            (
+                client.socket.clone(),
+                client.correlation.as_ref().map(ClientCorrelation::next_id),
+            )
+        };
+        let data: ResponseData = data.into();
+        let response: Response = match correlation_id {
+            Some(id) => Response::from(frame_correlation_id(id, &data)),
+            None => Response::from(data),
+        };
+        response.send_udp(&Some(socket), &Some(addr)).await
+    }
+
+    /// Sends a request to the connected peer and waits for its reply.
+    ///
+    /// # Arguments
+    ///
+    /// - `Into<ResponseData>` - The request payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, ResponseError>` - The reply, or
+    ///   `ResponseError::AddressNotAvailable` when the client is not connected.
+    pub async fn request<T>(&self, data: T) -> Result<Request, ResponseError>
+    where
+        T: Into<ResponseData>,
+    {
+        let peer: SocketAddr = self
+            .get_peer_addr()
+            .await
+            .ok_or(ResponseError::AddressNotAvailable)?;
+        self.request_to(data, peer).await
+    }
+
+    /// Sends a request to the given address and waits for its reply.
+    ///
+    /// The request is resent each time the configured timeout elapses without a
+    /// reply, up to the configured number of retries. Datagrams from other
+    /// addresses are ignored. Without correlation ID framing, replies are not
+    /// matched to requests, so concurrent requests through one client may receive
+    /// each other's replies. With framing enabled, every request carries its own
+    /// ID, retries reuse it, and any number of requests may be in flight at once.
+    /// On a connected client, a receive error fails every request waiting for a
+    /// reply from the connected peer; otherwise requests rely on their timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `Into<ResponseData>` - The request payload.
+    /// - `SocketAddr` - The destination address.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Request, ResponseError>` - The reply, or `ResponseError::Timeout`
+    ///   when every attempt timed out.
+    pub async fn request_to<T>(&self, data: T, addr: SocketAddr) -> Result<Request, ResponseError>
+    where
+        T: Into<ResponseData>,
+    {
+        let (socket, config, recv_pool, correlation): (
+            ArcUdpSocket,
+            ClientConfigData,
+            SharedBufferPool,
+            Option<(u32, PendingReplies)>,
+        ) = {
+            let client: RwLockReadGuard<'_, ClientData> = self.read().await;
+            (
+                client.socket.clone(),
+                client.config.clone(),
+                client.recv_pool.clone(),
+                client
+                    .correlation
+                    .as_ref()
+                    .map(|correlation: &ClientCorrelation| {
+                        (correlation.next_id(), correlation.pending.clone())
+                    }),
+            )
+        };
+        let data: ResponseData = data.into();
+        let socket_opt: Option<ArcUdpSocket> = Some(socket.clone());
+        if let Some((id, pending)) = correlation {
+            let (sender, mut receiver): (
+                oneshot::Sender<ReplyResult>,
+                oneshot::Receiver<ReplyResult>,
+            ) = oneshot::channel();
+            lock_pending(&pending).insert(id, PendingReply { addr, sender });
+            let _guard: PendingReplyGuard = PendingReplyGuard { pending, id };
+            let response: Response = Response::from(frame_correlation_id(id, &data));
+            for _ in 0..=config.get_retries() {
+                response.send_udp(&socket_opt, &Some(addr)).await?;
+                match timeout(config.get_timeout(), &mut receiver).await {
+                    Ok(Ok(reply)) => return reply,
+                    Ok(Err(error)) => return Err(ResponseError::ReceiveError(error.to_string())),
+                    Err(_) => {}
+                }
+            }
+            return Err(ResponseError::Timeout);
+        }
+        let response: Response = Response::from(data);
+        for _ in 0..=config.get_retries() {
+            response.send_udp(&socket_opt, &Some(addr)).await?;
+            let reply: Result<ReplyResult, Elapsed> = timeout(
+                config.get_timeout(),
+                Self::recv_reply(&socket, addr, &recv_pool),
+            )
+            .await;
+            if let Ok(reply) = reply {
+                return reply;
+            }
+        }
+        Err(ResponseError::Timeout)
+    }
+
+    /// Receives the next datagram sent by the given address.
+    ///
+    /// Datagrams are received into the client's shared buffer pool, which is
+    /// locked for the duration of the receive, so the reply is not copied.
+    ///
+    /// # Arguments
+    ///
+    /// - `&UdpSocket` - The client socket.
+    /// - `SocketAddr` - The address the reply must come from.
+    /// - `&AsyncMutex<BufferPool>` - The buffer pool to receive into.
+    ///
+    /// # Returns
+    ///
+    /// - `ReplyResult` - The reply payload.
+    async fn recv_reply(
+        socket: &UdpSocket,
+        addr: SocketAddr,
+        recv_pool: &AsyncMutex<BufferPool>,
+    ) -> ReplyResult {
+        let mut pool: AsyncMutexGuard<'_, BufferPool> = recv_pool.lock().await;
+        loop {
+            let mut buf: &mut [MaybeUninit<u8>] = pool.buffer();
+            let (len, from): (usize, SocketAddr) = socket
+                .recv_buf_from(&mut buf)
+                .await
+                .map_err(|error: std::io::Error| ResponseError::ReceiveError(error.to_string()))?;
+            if from == addr {
+                return Ok(pool.take(len));
+            }
+        }
+    }
+}
+
+/// Implementation of methods for `ClientCorrelation`.
+impl ClientCorrelation {
+    /// Hands out the next correlation ID.
+    ///
+    /// # Returns
+    ///
+    /// - `u32` - The correlation ID.
+    pub(crate) fn next_id(&self) -> u32 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+/// Implementation of Drop for PendingReplyGuard.
+impl Drop for PendingReplyGuard {
+    /// Forgets the pending reply so a late reply is discarded.
+    fn drop(&mut self) {
+        lock_pending(&self.pending).remove(&self.id);
+    }
+}
@@ -0,0 +1,10 @@
+mod r#fn;
+mod r#impl;
+mod r#struct;
+mod r#type;
+
+pub(crate) use {r#fn::*, r#type::*};
+
+pub use r#struct::*;
+
+use super::*;
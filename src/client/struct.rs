@@ -0,0 +1,51 @@
+use super::*;
+
+/// A request waiting for the reply carrying its correlation ID.
+#[derive(Debug)]
+pub(crate) struct PendingReply {
+    /// The address the request was sent to.
+    pub(super) addr: SocketAddr,
+    /// Receives the reply payload, or the receive error that failed the request.
+    pub(super) sender: oneshot::Sender<ReplyResult>,
+}
+
+/// Removes a pending reply when its request completes or is cancelled.
+pub(crate) struct PendingReplyGuard {
+    /// The pending reply map.
+    pub(super) pending: PendingReplies,
+    /// The correlation ID of the request.
+    pub(super) id: u32,
+}
+
+/// The correlation state of a client with framing enabled.
+#[derive(Debug)]
+pub(crate) struct ClientCorrelation {
+    /// The replies awaited by in-flight requests.
+    pub(super) pending: PendingReplies,
+    /// The next correlation ID to hand out.
+    pub(super) next_id: AtomicU32,
+    /// Stops the reply demultiplexing task when the client is dropped.
+    pub(super) _receiver: AbortOnDrop,
+}
+
+/// Represents the internal state of a UDP client.
+#[derive(Debug)]
+pub(crate) struct ClientData {
+    /// The bound client socket.
+    pub(super) socket: ArcUdpSocket,
+    /// The configuration the client was created with.
+    pub(super) config: ClientConfigData,
+    /// The peer the socket is connected to, if any.
+    pub(super) peer: Option<SocketAddr>,
+    /// The buffer replies are received into when correlation ID framing is disabled.
+    pub(super) recv_pool: SharedBufferPool,
+    /// The correlation state, if correlation ID framing is enabled.
+    pub(super) correlation: Option<ClientCorrelation>,
+}
+
+/// An asynchronous UDP client for talking to a `Server`.
+///
+/// The client sends `Response` payloads and receives replies as `Request`
+/// buffers. Cloning a client shares its socket.
+#[derive(Clone, Debug)]
+pub struct Client(pub(super) ArcRwLock<ClientData>);
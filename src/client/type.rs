@@ -0,0 +1,10 @@
+use super::*;
+
+/// The replies awaited by in-flight requests, keyed by correlation ID.
+pub(crate) type PendingReplies = Arc<Mutex<HashMap<u32, PendingReply>>>;
+
+/// The reply handed to a waiting request, or the receive error that ended the wait.
+pub(crate) type ReplyResult = Result<Request, ResponseError>;
+
+/// The buffer pool shared by the uncorrelated requests of a client.
+pub(crate) type SharedBufferPool = Arc<AsyncMutex<BufferPool>>;
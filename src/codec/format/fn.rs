@@ -0,0 +1,107 @@
+use super::*;
+
+/// Deserializes a JSON payload.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The JSON payload.
+///
+/// # Returns
+///
+/// - `Result<T, String>` - The deserialized value, or the error message.
+#[cfg(feature = "json")]
+pub(crate) fn decode_json<T>(data: &[u8]) -> Result<T, String>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_slice(data).map_err(|error: serde_json::Error| error.to_string())
+}
+
+/// Serializes a value as JSON.
+///
+/// # Arguments
+///
+/// - `&T` - The value to serialize.
+///
+/// # Returns
+///
+/// - `Result<ResponseData, String>` - The JSON payload, or the error message.
+#[cfg(feature = "json")]
+pub(crate) fn encode_json<T>(value: &T) -> Result<ResponseData, String>
+where
+    T: Serialize + ?Sized,
+{
+    serde_json::to_vec(value).map_err(|error: serde_json::Error| error.to_string())
+}
+
+/// Deserializes a CBOR payload.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The CBOR payload.
+///
+/// # Returns
+///
+/// - `Result<T, String>` - The deserialized value, or the error message.
+#[cfg(feature = "cbor")]
+pub(crate) fn decode_cbor<T>(data: &[u8]) -> Result<T, String>
+where
+    T: DeserializeOwned,
+{
+    ciborium::from_reader(data)
+        .map_err(|error: ciborium::de::Error<std::io::Error>| error.to_string())
+}
+
+/// Serializes a value as CBOR.
+///
+/// # Arguments
+///
+/// - `&T` - The value to serialize.
+///
+/// # Returns
+///
+/// - `Result<ResponseData, String>` - The CBOR payload, or the error message.
+#[cfg(feature = "cbor")]
+pub(crate) fn encode_cbor<T>(value: &T) -> Result<ResponseData, String>
+where
+    T: Serialize + ?Sized,
+{
+    let mut data: ResponseData = Vec::new();
+    ciborium::into_writer(value, &mut data)
+        .map_err(|error: ciborium::ser::Error<std::io::Error>| error.to_string())?;
+    Ok(data)
+}
+
+/// Deserializes a MessagePack payload.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The MessagePack payload.
+///
+/// # Returns
+///
+/// - `Result<T, String>` - The deserialized value, or the error message.
+#[cfg(feature = "msgpack")]
+pub(crate) fn decode_msgpack<T>(data: &[u8]) -> Result<T, String>
+where
+    T: DeserializeOwned,
+{
+    rmp_serde::from_slice(data).map_err(|error: rmp_serde::decode::Error| error.to_string())
+}
+
+/// Serializes a value as MessagePack, encoding structs as maps.
+///
+/// # Arguments
+///
+/// - `&T` - The value to serialize.
+///
+/// # Returns
+///
+/// - `Result<ResponseData, String>` - The MessagePack payload, or the error message.
+#[cfg(feature = "msgpack")]
+pub(crate) fn encode_msgpack<T>(value: &T) -> Result<ResponseData, String>
+where
+    T: Serialize + ?Sized,
+{
+    rmp_serde::to_vec_named(value).map_err(|error: rmp_serde::encode::Error| error.to_string())
+}
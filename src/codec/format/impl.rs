@@ -0,0 +1,310 @@
+use super::*;
+
+/// Implementation of methods for `JsonCodec`.
+#[cfg(feature = "json")]
+impl<Req, Res> JsonCodec<Req, Res> {
+    /// Creates a new JSON codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `JsonCodec` instance.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Provides a default implementation for `JsonCodec`.
+#[cfg(feature = "json")]
+impl<Req, Res> Default for JsonCodec<Req, Res> {
+    /// Creates a new JSON codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `JsonCodec` instance.
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Provides a clone implementation for `JsonCodec` that does not require `Req` or `Res` to be `Clone`.
+#[cfg(feature = "json")]
+impl<Req, Res> Clone for JsonCodec<Req, Res> {
+    /// Returns a copy of the codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `JsonCodec` instance.
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// Marks `JsonCodec` as `Copy` regardless of `Req` and `Res`.
+#[cfg(feature = "json")]
+impl<Req, Res> Copy for JsonCodec<Req, Res> {}
+
+/// Provides a debug implementation for `JsonCodec` that names its type parameters.
+#[cfg(feature = "json")]
+impl<Req, Res> fmt::Debug for JsonCodec<Req, Res> {
+    /// Formats the codec with its request and response types.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut fmt::Formatter<'_>` - The formatter to write to.
+    ///
+    /// # Returns
+    ///
+    /// - `fmt::Result` - The result of the formatting operation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsonCodec")
+            .field("request", &type_name::<Req>())
+            .field("response", &type_name::<Res>())
+            .finish()
+    }
+}
+
+/// Implements `Codec` for `JsonCodec` with serde.
+#[cfg(feature = "json")]
+impl<Req, Res> Codec for JsonCodec<Req, Res>
+where
+    Req: DeserializeOwned + Send + Sync + 'static,
+    Res: Serialize + 'static,
+{
+    type Request = Req;
+    type Response = Res;
+    type Error = String;
+
+    /// Deserializes a JSON request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The JSON payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Req, String>` - The decoded request, or the error message.
+    fn decode(&self, data: &[u8]) -> Result<Req, String> {
+        decode_json(data)
+    }
+
+    /// Serializes a response as JSON.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Res` - The response to encode.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ResponseData, String>` - The JSON payload, or the error message.
+    fn encode(&self, response: &Res) -> Result<ResponseData, String> {
+        encode_json(response)
+    }
+}
+
+/// Implementation of methods for `CborCodec`.
+#[cfg(feature = "cbor")]
+impl<Req, Res> CborCodec<Req, Res> {
+    /// Creates a new CBOR codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `CborCodec` instance.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Provides a default implementation for `CborCodec`.
+#[cfg(feature = "cbor")]
+impl<Req, Res> Default for CborCodec<Req, Res> {
+    /// Creates a new CBOR codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `CborCodec` instance.
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Provides a clone implementation for `CborCodec` that does not require `Req` or `Res` to be `Clone`.
+#[cfg(feature = "cbor")]
+impl<Req, Res> Clone for CborCodec<Req, Res> {
+    /// Returns a copy of the codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `CborCodec` instance.
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// Marks `CborCodec` as `Copy` regardless of `Req` and `Res`.
+#[cfg(feature = "cbor")]
+impl<Req, Res> Copy for CborCodec<Req, Res> {}
+
+/// Provides a debug implementation for `CborCodec` that names its type parameters.
+#[cfg(feature = "cbor")]
+impl<Req, Res> fmt::Debug for CborCodec<Req, Res> {
+    /// Formats the codec with its request and response types.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut fmt::Formatter<'_>` - The formatter to write to.
+    ///
+    /// # Returns
+    ///
+    /// - `fmt::Result` - The result of the formatting operation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CborCodec")
+            .field("request", &type_name::<Req>())
+            .field("response", &type_name::<Res>())
+            .finish()
+    }
+}
+
+/// Implements `Codec` for `CborCodec` with serde.
+#[cfg(feature = "cbor")]
+impl<Req, Res> Codec for CborCodec<Req, Res>
+where
+    Req: DeserializeOwned + Send + Sync + 'static,
+    Res: Serialize + 'static,
+{
+    type Request = Req;
+    type Response = Res;
+    type Error = String;
+
+    /// Deserializes a CBOR request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The CBOR payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Req, String>` - The decoded request, or the error message.
+    fn decode(&self, data: &[u8]) -> Result<Req, String> {
+        decode_cbor(data)
+    }
+
+    /// Serializes a response as CBOR.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Res` - The response to encode.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ResponseData, String>` - The CBOR payload, or the error message.
+    fn encode(&self, response: &Res) -> Result<ResponseData, String> {
+        encode_cbor(response)
+    }
+}
+
+/// Implementation of methods for `MsgPackCodec`.
+#[cfg(feature = "msgpack")]
+impl<Req, Res> MsgPackCodec<Req, Res> {
+    /// Creates a new MessagePack codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `MsgPackCodec` instance.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Provides a default implementation for `MsgPackCodec`.
+#[cfg(feature = "msgpack")]
+impl<Req, Res> Default for MsgPackCodec<Req, Res> {
+    /// Creates a new MessagePack codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `MsgPackCodec` instance.
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Provides a clone implementation for `MsgPackCodec` that does not require `Req` or `Res` to be `Clone`.
+#[cfg(feature = "msgpack")]
+impl<Req, Res> Clone for MsgPackCodec<Req, Res> {
+    /// Returns a copy of the codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `MsgPackCodec` instance.
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// Marks `MsgPackCodec` as `Copy` regardless of `Req` and `Res`.
+#[cfg(feature = "msgpack")]
+impl<Req, Res> Copy for MsgPackCodec<Req, Res> {}
+
+/// Provides a debug implementation for `MsgPackCodec` that names its type parameters.
+#[cfg(feature = "msgpack")]
+impl<Req, Res> fmt::Debug for MsgPackCodec<Req, Res> {
+    /// Formats the codec with its request and response types.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut fmt::Formatter<'_>` - The formatter to write to.
+    ///
+    /// # Returns
+    ///
+    /// - `fmt::Result` - The result of the formatting operation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MsgPackCodec")
+            .field("request", &type_name::<Req>())
+            .field("response", &type_name::<Res>())
+            .finish()
+    }
+}
+
+/// Implements `Codec` for `MsgPackCodec` with serde.
+#[cfg(feature = "msgpack")]
+impl<Req, Res> Codec for MsgPackCodec<Req, Res>
+where
+    Req: DeserializeOwned + Send + Sync + 'static,
+    Res: Serialize + 'static,
+{
+    type Request = Req;
+    type Response = Res;
+    type Error = String;
+
+    /// Deserializes a MessagePack request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The MessagePack payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Req, String>` - The decoded request, or the error message.
+    fn decode(&self, data: &[u8]) -> Result<Req, String> {
+        decode_msgpack(data)
+    }
+
+    /// Serializes a response as MessagePack.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Res` - The response to encode.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ResponseData, String>` - The MessagePack payload, or the error message.
+    fn encode(&self, response: &Res) -> Result<ResponseData, String> {
+        encode_msgpack(response)
+    }
+}
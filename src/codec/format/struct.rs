@@ -0,0 +1,14 @@
+use super::*;
+
+/// A `Codec` that decodes JSON requests into `Req` and encodes `Res` replies as JSON.
+#[cfg(feature = "json")]
+pub struct JsonCodec<Req, Res = Req>(pub(super) PhantomData<fn() -> (Req, Res)>);
+
+/// A `Codec` that decodes CBOR requests into `Req` and encodes `Res` replies as CBOR.
+#[cfg(feature = "cbor")]
+pub struct CborCodec<Req, Res = Req>(pub(super) PhantomData<fn() -> (Req, Res)>);
+
+/// A `Codec` that decodes MessagePack requests into `Req` and encodes `Res` replies
+/// as MessagePack.
+#[cfg(feature = "msgpack")]
+pub struct MsgPackCodec<Req, Res = Req>(pub(super) PhantomData<fn() -> (Req, Res)>);
@@ -0,0 +1,42 @@
+use super::*;
+
+/// Blanket implementation of `ErasedCodec` for every `Codec`.
+impl<C> ErasedCodec for C
+where
+    C: Codec,
+{
+    /// Decodes a request payload into a type-erased value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The request payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<DecodedRequest, RequestError>` - The decoded request.
+    fn decode_erased(&self, data: &[u8]) -> Result<DecodedRequest, RequestError> {
+        self.decode(data)
+            .map(|request: C::Request| Arc::new(request) as DecodedRequest)
+            .map_err(|error: C::Error| RequestError::DecodeError(error.to_string()))
+    }
+
+    /// Encodes a type-erased response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&dyn Any` - The response.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ResponseData, ResponseError>` - The encoded payload.
+    fn encode_erased(&self, response: &dyn Any) -> Result<ResponseData, ResponseError> {
+        let response: &C::Response = response.downcast_ref::<C::Response>().ok_or_else(|| {
+            ResponseError::EncodeError(format!(
+                "expected response type {}",
+                type_name::<C::Response>()
+            ))
+        })?;
+        self.encode(response)
+            .map_err(|error: C::Error| ResponseError::EncodeError(error.to_string()))
+    }
+}
@@ -0,0 +1,14 @@
+#[cfg(any(feature = "json", feature = "cbor", feature = "msgpack"))]
+mod format;
+mod r#impl;
+mod r#trait;
+mod r#type;
+
+#[cfg(any(feature = "json", feature = "cbor", feature = "msgpack"))]
+pub use format::*;
+
+pub(crate) use r#type::*;
+
+pub use r#trait::*;
+
+use super::*;
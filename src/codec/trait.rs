@@ -0,0 +1,65 @@
+use super::*;
+
+/// Converts raw datagrams into typed requests and typed responses into datagrams.
+///
+/// A codec attached with `Server::codec` decodes every request before the hooks
+/// run. Handlers read the decoded value with `Context::get_decoded` and reply with
+/// `Context::send_encoded`. Datagrams that fail to decode are passed to the
+/// decode error hooks instead of the request hooks.
+pub trait Codec: Send + Sync + 'static {
+    /// The decoded request type.
+    type Request: Send + Sync + 'static;
+    /// The response type encoded into replies.
+    type Response: 'static;
+    /// The error returned when decoding or encoding fails.
+    type Error: Display;
+
+    /// Decodes a request payload.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The request payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self::Request, Self::Error>` - The decoded request.
+    fn decode(&self, data: &[u8]) -> Result<Self::Request, Self::Error>;
+
+    /// Encodes a response into a datagram payload.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Self::Response` - The response to encode.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ResponseData, Self::Error>` - The encoded payload.
+    fn encode(&self, response: &Self::Response) -> Result<ResponseData, Self::Error>;
+}
+
+/// A type-erased `Codec` that can be stored without its associated types.
+pub(crate) trait ErasedCodec: Send + Sync {
+    /// Decodes a request payload into a type-erased value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The request payload.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<DecodedRequest, RequestError>` - The decoded request, or
+    ///   `RequestError::DecodeError`.
+    fn decode_erased(&self, data: &[u8]) -> Result<DecodedRequest, RequestError>;
+
+    /// Encodes a type-erased response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&dyn Any` - The response, which must be the codec's response type.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ResponseData, ResponseError>` - The encoded payload, or
+    ///   `ResponseError::EncodeError`.
+    fn encode_erased(&self, response: &dyn Any) -> Result<ResponseData, ResponseError>;
+}
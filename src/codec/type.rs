@@ -0,0 +1,7 @@
+use super::*;
+
+/// A type alias for a decoded request stored on the context.
+pub(crate) type DecodedRequest = Arc<dyn Any + Send + Sync>;
+
+/// A type alias for a codec shared by the server and its contexts.
+pub(crate) type SharedCodec = Arc<dyn ErasedCodec>;
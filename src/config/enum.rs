@@ -0,0 +1,27 @@
+/// Policy applied when a received datagram is larger than the receive buffer.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TruncationPolicy {
+    /// Discard the datagram and report `RequestError::BufferTooSmall` to the read error hooks.
+    #[default]
+    Drop,
+    /// Deliver the truncated datagram with the context's truncated flag set.
+    Deliver,
+    /// Peek at each datagram and grow the receive buffer until it fits, up to `MAX_DATAGRAM_SIZE`.
+    Grow,
+}
+
+/// Policy applied when a datagram arrives while the maximum number of handlers is already running.
+///
+/// Only request handlers are subject to it. Read error and rate limited hooks run
+/// on their own bounded trackers and are always skipped, never blocked on, when
+/// their budget is exhausted, so the receive loop never waits for them.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum OverloadPolicy {
+    /// Discard the incoming datagram.
+    #[default]
+    DropNewest,
+    /// Cancel the longest-running handler to make room for the incoming datagram.
+    DropOldest,
+    /// Stop receiving until a handler finishes, leaving datagrams queued in the socket.
+    Block,
+}
@@ -6,10 +6,68 @@ pub const DEFAULT_HOST: &str = "0.0.0.0";
 pub const DEFAULT_PORT: u16 = 60000;
 /// Default buffer size for UDP packets (512KB).
 pub const DEFAULT_BUFFER_SIZE: usize = 524288;
+/// Largest possible UDP payload plus one byte, used to cap buffer growth.
+pub const MAX_DATAGRAM_SIZE: usize = 65536;
+/// Default number of datagrams per receive or send system call; `1` disables batching.
+pub const DEFAULT_BATCH_SIZE: usize = 1;
+/// Default policy for datagrams larger than the receive buffer.
+pub const DEFAULT_TRUNCATION_POLICY: TruncationPolicy = TruncationPolicy::Drop;
+/// Default auto-flush setting.
+pub const DEFAULT_AUTO_FLUSH: bool = false;
+/// Default `UDP_GRO` setting.
+pub const DEFAULT_GRO: bool = false;
+/// Default correlation ID framing setting.
+pub const DEFAULT_CORRELATION_IDS: bool = false;
+/// Default maximum number of concurrently running request handlers.
+pub const DEFAULT_MAX_IN_FLIGHT: Option<usize> = None;
+/// Default policy when the maximum number of request handlers is reached.
+pub const DEFAULT_OVERLOAD_POLICY: OverloadPolicy = OverloadPolicy::DropNewest;
+/// Default maximum number of concurrently running read error hook chains.
+pub const DEFAULT_MAX_READ_ERROR_IN_FLIGHT: usize = 16;
+/// Default per-source rate limit.
+pub const DEFAULT_RATE_LIMIT: Option<RateLimit> = None;
+/// Default maximum number of rate limited hook chains running at once.
+pub const DEFAULT_RATE_LIMITED_IN_FLIGHT: usize = 16;
+/// Default maximum number of sources the rate limiter tracks at once.
+pub const DEFAULT_RATE_LIMIT_MAX_SOURCES: usize = 65536;
+/// Default number of receive sockets per bind address.
+pub const DEFAULT_WORKERS: usize = 1;
+/// Default setting for running receive loops on pinned current-thread runtimes.
+pub const DEFAULT_PIN_WORKERS: bool = false;
+/// Default deadline for draining in-flight handlers during a graceful shutdown.
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+/// Default deadline for the hook chain of a single request.
+pub const DEFAULT_REQUEST_TIMEOUT: Option<Duration> = None;
+/// Default time a session may stay idle before it expires.
+pub const DEFAULT_SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+/// Shortest time a session may stay idle before it expires.
+pub const MIN_SESSION_IDLE_TIMEOUT: Duration = Duration::from_millis(1);
+/// Default maximum number of concurrent sessions.
+pub const DEFAULT_MAX_SESSIONS: Option<usize> = None;
+/// Default local address of a client socket.
+pub const DEFAULT_CLIENT_BIND: &str = "0.0.0.0:0";
+/// Default time a client request waits for each reply.
+pub const DEFAULT_CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Default number of times a client request is resent after a timeout.
+pub const DEFAULT_CLIENT_RETRIES: u32 = 2;
 /// Default `TCP_NODELAY` setting.
 pub const DEFAULT_NODELAY: Option<bool> = None;
 /// Default `IP_TTL` setting.
 pub const DEFAULT_TTL: Option<u32> = None;
+/// Default `SO_REUSEADDR` setting.
+pub const DEFAULT_REUSE_ADDRESS: Option<bool> = None;
+/// Default `SO_REUSEPORT` setting.
+pub const DEFAULT_REUSE_PORT: Option<bool> = None;
+/// Default `SO_RCVBUF` setting.
+pub const DEFAULT_RECV_BUFFER_SIZE: Option<usize> = None;
+/// Default `SO_SNDBUF` setting.
+pub const DEFAULT_SEND_BUFFER_SIZE: Option<usize> = None;
+/// Default `IP_TOS` setting.
+pub const DEFAULT_TOS: Option<u32> = None;
+/// Default `IPV6_V6ONLY` setting.
+pub const DEFAULT_ONLY_V6: Option<bool> = None;
+/// Default `SO_BROADCAST` setting.
+pub const DEFAULT_BROADCAST: Option<bool> = None;
 
 /// Provides a default implementation for ServerConfigData.
 impl Default for ServerConfigData {
@@ -23,9 +81,32 @@ impl Default for ServerConfigData {
         Self {
             host: DEFAULT_HOST.to_owned(),
             port: DEFAULT_PORT,
+            listen: Vec::new(),
             buffer_size: DEFAULT_BUFFER_SIZE,
+            batch_size: DEFAULT_BATCH_SIZE,
+            truncation_policy: DEFAULT_TRUNCATION_POLICY,
+            auto_flush: DEFAULT_AUTO_FLUSH,
+            gro: DEFAULT_GRO,
+            correlation_ids: DEFAULT_CORRELATION_IDS,
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+            overload_policy: DEFAULT_OVERLOAD_POLICY,
+            max_read_error_in_flight: DEFAULT_MAX_READ_ERROR_IN_FLIGHT,
+            rate_limit: DEFAULT_RATE_LIMIT,
+            workers: DEFAULT_WORKERS,
+            pin_workers: DEFAULT_PIN_WORKERS,
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            session_idle_timeout: DEFAULT_SESSION_IDLE_TIMEOUT,
+            max_sessions: DEFAULT_MAX_SESSIONS,
             nodelay: DEFAULT_NODELAY,
             ttl: DEFAULT_TTL,
+            reuse_address: DEFAULT_REUSE_ADDRESS,
+            reuse_port: DEFAULT_REUSE_PORT,
+            recv_buffer_size: DEFAULT_RECV_BUFFER_SIZE,
+            send_buffer_size: DEFAULT_SEND_BUFFER_SIZE,
+            tos: DEFAULT_TOS,
+            only_v6: DEFAULT_ONLY_V6,
+            broadcast: DEFAULT_BROADCAST,
         }
     }
 }
@@ -166,6 +247,26 @@ impl ServerConfig {
         self
     }
 
+    /// Adds a `host:port` address for the server to listen on.
+    ///
+    /// Once any listen address is added, the server binds every listen address
+    /// instead of the single `host` and `port`.
+    ///
+    /// # Arguments
+    ///
+    /// - `A` - The `host:port` address to add, e.g. `"[::]:5353"`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn listen<A>(&self, addr: A) -> &Self
+    where
+        A: AsRef<str>,
+    {
+        self.write().await.listen.push(addr.as_ref().to_owned());
+        self
+    }
+
     /// Sets the buffer size for receiving UDP packets.
     ///
     /// # Arguments
@@ -180,6 +281,264 @@ impl ServerConfig {
         self
     }
 
+    /// Sets the maximum number of datagrams received or sent per system call.
+    ///
+    /// Values above one enable batching: on Linux each receive loop pulls up to
+    /// this many datagrams per `recvmmsg` into a pool of buffers, and replies are
+    /// coalesced into `sendmmsg` calls. Other platforms fall back to one datagram
+    /// per call. Batched receives are not used with `TruncationPolicy::Grow`.
+    ///
+    /// # Arguments
+    ///
+    /// - `batch_size` - The batch size; `0` is treated as `1`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn batch_size(&self, batch_size: usize) -> &Self {
+        self.write().await.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Sets the policy applied to datagrams larger than the receive buffer.
+    ///
+    /// Truncation is detected on Unix platforms only.
+    ///
+    /// # Arguments
+    ///
+    /// - `policy` - The truncation policy.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn truncation_policy(&self, policy: TruncationPolicy) -> &Self {
+        self.write().await.truncation_policy = policy;
+        self
+    }
+
+    /// Sets whether the accumulated context response is sent automatically.
+    ///
+    /// When enabled, the response built with `Context::set_response` and
+    /// `Context::append_response` is sent after the last hook, unless the
+    /// context was aborted, a reply was already sent, or the response is empty.
+    ///
+    /// # Arguments
+    ///
+    /// - `auto_flush` - Whether to enable auto-flush.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn auto_flush(&self, auto_flush: bool) -> &Self {
+        self.write().await.auto_flush = auto_flush;
+        self
+    }
+
+    /// Sets whether `UDP_GRO` is enabled on the listening sockets.
+    ///
+    /// With GRO the kernel may deliver several datagrams from one peer in a single
+    /// buffer; the server splits them back into one request per datagram. Binding
+    /// fails on platforms without `UDP_GRO`.
+    ///
+    /// # Arguments
+    ///
+    /// - `gro` - Whether to enable GRO.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn gro(&self, gro: bool) -> &Self {
+        self.write().await.gro = gro;
+        self
+    }
+
+    /// Sets whether datagrams are framed with a correlation ID.
+    ///
+    /// When enabled, every datagram must start with a `CORRELATION_ID_LEN` byte
+    /// big-endian request ID. The ID is stripped from the request and prepended to
+    /// every reply sent through the context. Shorter datagrams are reported to the
+    /// read error hooks as `RequestError::MissingCorrelationId`.
+    ///
+    /// # Arguments
+    ///
+    /// - `correlation_ids` - Whether to enable correlation ID framing.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn correlation_ids(&self, correlation_ids: bool) -> &Self {
+        self.write().await.correlation_ids = correlation_ids;
+        self
+    }
+
+    /// Sets the maximum number of request handlers that may run at once.
+    ///
+    /// # Arguments
+    ///
+    /// - `max` - The handler limit.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn max_in_flight(&self, max: usize) -> &Self {
+        self.write().await.max_in_flight = Some(max);
+        self
+    }
+
+    /// Sets the policy applied when the handler limit is reached.
+    ///
+    /// The policy applies to request handlers only; read error and rate limited
+    /// hooks are skipped when their own budget is exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// - `policy` - The overload policy.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn overload_policy(&self, policy: OverloadPolicy) -> &Self {
+        self.write().await.overload_policy = policy;
+        self
+    }
+
+    /// Sets the maximum number of read error hook chains that may run at once.
+    ///
+    /// Read error hooks run apart from the request handlers and never take a
+    /// handler slot; receive errors beyond this budget are counted by
+    /// `ServerControlHook::read_errors_dropped` and skip the hooks.
+    ///
+    /// # Arguments
+    ///
+    /// - `max` - The read error hook limit.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn max_read_error_in_flight(&self, max: usize) -> &Self {
+        self.write().await.max_read_error_in_flight = max;
+        self
+    }
+
+    /// Sets the per-source rate limit.
+    ///
+    /// Datagrams over the limit are rejected in the receive loop before a handler
+    /// is spawned and passed to the `rate_limited` hooks, if any.
+    ///
+    /// # Arguments
+    ///
+    /// - `rate_limit` - The rate limit.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn rate_limit(&self, rate_limit: RateLimit) -> &Self {
+        self.write().await.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Sets the number of receive sockets opened on each bind address.
+    ///
+    /// With more than one worker, `SO_REUSEPORT` is enabled and the kernel spreads
+    /// incoming datagrams across the sockets, each drained by its own receive loop.
+    /// All loops share the same hooks. Values below `1` are treated as `1`.
+    ///
+    /// # Arguments
+    ///
+    /// - `workers` - The number of sockets per bind address.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn workers(&self, workers: usize) -> &Self {
+        self.write().await.workers = workers.max(1);
+        self
+    }
+
+    /// Sets whether each receive loop runs on a dedicated, core-pinned thread.
+    ///
+    /// When enabled, every socket gets its own thread running a current-thread
+    /// runtime, and the handlers for its datagrams run on that runtime. On Linux the
+    /// threads are pinned to the available cores in turn.
+    ///
+    /// # Arguments
+    ///
+    /// - `pin_workers` - Whether to pin receive loops to dedicated threads.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn pin_workers(&self, pin_workers: bool) -> &Self {
+        self.write().await.pin_workers = pin_workers;
+        self
+    }
+
+    /// Sets how long a graceful shutdown waits for in-flight handlers.
+    ///
+    /// Handlers still running after the deadline are cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// - `timeout` - The drain deadline.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn shutdown_timeout(&self, timeout: Duration) -> &Self {
+        self.write().await.shutdown_timeout = timeout;
+        self
+    }
+
+    /// Sets the deadline for the hook chain of each request.
+    ///
+    /// When it elapses, the chain is cancelled, the context is marked aborted
+    /// and the timeout hooks run. Routes may override it.
+    ///
+    /// # Arguments
+    ///
+    /// - `timeout` - The per-request deadline.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn request_timeout(&self, timeout: Duration) -> &Self {
+        self.write().await.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how long a session may stay idle before it expires.
+    ///
+    /// The timeout is at least `MIN_SESSION_IDLE_TIMEOUT`.
+    ///
+    /// # Arguments
+    ///
+    /// - `timeout` - The session idle timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn session_idle_timeout(&self, timeout: Duration) -> &Self {
+        self.write().await.session_idle_timeout = timeout.max(MIN_SESSION_IDLE_TIMEOUT);
+        self
+    }
+
+    /// Sets the maximum number of concurrent sessions.
+    ///
+    /// Once reached, idle sessions are evicted to make room; if none are idle,
+    /// `Context::try_get_session` returns `None` for new clients.
+    ///
+    /// # Arguments
+    ///
+    /// - `max` - The session limit.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn max_sessions(&self, max: usize) -> &Self {
+        self.write().await.max_sessions = Some(max);
+        self
+    }
+
     /// Sets the `TCP_NODELAY` option.
     ///
     /// # Arguments
@@ -225,100 +584,1029 @@ impl ServerConfig {
         self.write().await.ttl = Some(ttl);
         self
     }
-}
 
-/// Implementation block for `ServerConfigData`.
-impl ServerConfigData {
-    /// Gets the host address.
+    /// Sets the `SO_REUSEADDR` option.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// - `&String` - Reference to the host address.
-    pub fn get_host(&self) -> &String {
-        &self.host
-    }
-
-    /// Gets the port number.
+    /// - `reuse_address` - The value for `SO_REUSEADDR`.
     ///
     /// # Returns
     ///
-    /// - `u16` - The port number.
-    pub fn get_port(&self) -> u16 {
-        self.port
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn reuse_address(&self, reuse_address: bool) -> &Self {
+        self.write().await.reuse_address = Some(reuse_address);
+        self
     }
 
-    /// Gets the buffer size.
+    /// Sets the `SO_REUSEPORT` option.
+    ///
+    /// Binding fails with `ServerError::UdpBind` on platforms without `SO_REUSEPORT`.
+    ///
+    /// # Arguments
+    ///
+    /// - `reuse_port` - The value for `SO_REUSEPORT`.
     ///
     /// # Returns
     ///
-    /// - `usize` - The buffer size in bytes.
-    pub fn get_buffer_size(&self) -> usize {
-        self.buffer_size
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn reuse_port(&self, reuse_port: bool) -> &Self {
+        self.write().await.reuse_port = Some(reuse_port);
+        self
     }
 
-    /// Gets the `TCP_NODELAY` option.
+    /// Sets the `SO_RCVBUF` kernel receive buffer size.
+    ///
+    /// # Arguments
+    ///
+    /// - `size` - The receive buffer size in bytes.
     ///
     /// # Returns
     ///
-    /// - `Option<bool>` - The `TCP_NODELAY` value if set.
-    pub fn get_nodelay(&self) -> Option<bool> {
-        self.nodelay
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn recv_buffer_size(&self, size: usize) -> &Self {
+        self.write().await.recv_buffer_size = Some(size);
+        self
     }
 
-    /// Gets the `IP_TTL` option.
+    /// Sets the `SO_SNDBUF` kernel send buffer size.
+    ///
+    /// # Arguments
+    ///
+    /// - `size` - The send buffer size in bytes.
     ///
     /// # Returns
     ///
-    /// - `Option<u32>` - The `IP_TTL` value if set.
-    pub fn get_ttl(&self) -> Option<u32> {
-        self.ttl
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn send_buffer_size(&self, size: usize) -> &Self {
+        self.write().await.send_buffer_size = Some(size);
+        self
     }
 
-    /// Sets the host address.
+    /// Sets the `IP_TOS` option (`IPV6_TCLASS` for IPv6 sockets).
     ///
     /// # Arguments
     ///
-    /// - `AsRef<str>` - The host address to set.
-    pub fn set_host<H>(&mut self, host: H)
-    where
-        H: AsRef<str>,
-    {
-        self.host = host.as_ref().to_owned();
+    /// - `tos` - The type-of-service byte.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn tos(&self, tos: u32) -> &Self {
+        self.write().await.tos = Some(tos);
+        self
     }
 
-    /// Sets the port number.
+    /// Sets the DSCP code point, stored as the upper six bits of `IP_TOS`.
     ///
     /// # Arguments
     ///
-    /// - `u16` - The port number to set.
-    pub fn set_port(&mut self, port: u16) {
-        self.port = port;
+    /// - `dscp` - The DSCP value (0-63).
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn dscp(&self, dscp: u8) -> &Self {
+        self.tos(u32::from(dscp & 0x3f) << 2).await
     }
 
-    /// Sets the buffer size.
+    /// Sets the `IPV6_V6ONLY` option, applied to IPv6 sockets only.
     ///
     /// # Arguments
     ///
-    /// - `usize` - The buffer size in bytes.
-    pub fn set_buffer_size(&mut self, size: usize) {
-        self.buffer_size = size;
+    /// - `only_v6` - The value for `IPV6_V6ONLY`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn only_v6(&self, only_v6: bool) -> &Self {
+        self.write().await.only_v6 = Some(only_v6);
+        self
     }
 
-    /// Sets the `TCP_NODELAY` option.
+    /// Sets the `SO_BROADCAST` option.
     ///
     /// # Arguments
     ///
-    /// - `Option<bool>` - The `TCP_NODELAY` value.
-    pub fn set_nodelay(&mut self, nodelay: Option<bool>) {
-        self.nodelay = nodelay;
-    }
+    /// - `broadcast` - The value for `SO_BROADCAST`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn broadcast(&self, broadcast: bool) -> &Self {
+        self.write().await.broadcast = Some(broadcast);
+        self
+    }
+}
+
+/// Implementation block for `ServerConfigData`.
+impl ServerConfigData {
+    /// Gets the host address.
+    ///
+    /// # Returns
+    ///
+    /// - `&String` - Reference to the host address.
+    pub fn get_host(&self) -> &String {
+        &self.host
+    }
+
+    /// Gets the port number.
+    ///
+    /// # Returns
+    ///
+    /// - `u16` - The port number.
+    pub fn get_port(&self) -> u16 {
+        self.port
+    }
+
+    /// Gets the additional listen addresses.
+    ///
+    /// # Returns
+    ///
+    /// - `&Vec<String>` - Reference to the listen addresses.
+    pub fn get_listen(&self) -> &Vec<String> {
+        &self.listen
+    }
+
+    /// Gets every `host:port` address the server will bind.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - The listen addresses, or `host:port` when none are set.
+    pub fn get_bind_addrs(&self) -> Vec<String> {
+        if self.listen.is_empty() {
+            return vec![format!("{}:{}", self.host, self.port)];
+        }
+        self.listen.clone()
+    }
+
+    /// Gets the buffer size.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The buffer size in bytes.
+    pub fn get_buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Gets the maximum number of datagrams per system call.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The batch size.
+    pub fn get_batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Gets the truncation policy.
+    ///
+    /// # Returns
+    ///
+    /// - `TruncationPolicy` - The policy applied to oversized datagrams.
+    pub fn get_truncation_policy(&self) -> TruncationPolicy {
+        self.truncation_policy
+    }
+
+    /// Gets the auto-flush setting.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the accumulated response is sent automatically.
+    pub fn get_auto_flush(&self) -> bool {
+        self.auto_flush
+    }
+
+    /// Gets the `UDP_GRO` setting.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether GRO is enabled on the listening sockets.
+    pub fn get_gro(&self) -> bool {
+        self.gro
+    }
+
+    /// Gets the correlation ID framing setting.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether datagrams carry a correlation ID.
+    pub fn get_correlation_ids(&self) -> bool {
+        self.correlation_ids
+    }
+
+    /// Gets the maximum number of concurrently running request handlers.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The handler limit, or `None` when unbounded.
+    pub fn get_max_in_flight(&self) -> Option<usize> {
+        self.max_in_flight
+    }
+
+    /// Gets the policy applied when the handler limit is reached.
+    ///
+    /// # Returns
+    ///
+    /// - `OverloadPolicy` - The overload policy.
+    pub fn get_overload_policy(&self) -> OverloadPolicy {
+        self.overload_policy
+    }
+
+    /// Gets the maximum number of concurrently running read error hook chains.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The read error hook limit.
+    pub fn get_max_read_error_in_flight(&self) -> usize {
+        self.max_read_error_in_flight
+    }
+
+    /// Gets the per-source rate limit.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RateLimit>` - The rate limit if set.
+    pub fn get_rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit
+    }
+
+    /// Gets the graceful shutdown drain deadline.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The drain deadline.
+    pub fn get_shutdown_timeout(&self) -> Duration {
+        self.shutdown_timeout
+    }
+
+    /// Gets the deadline for the hook chain of each request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The per-request deadline, or `None` when unbounded.
+    pub fn get_request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    /// Gets how long a session may stay idle before it expires.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The session idle timeout.
+    pub fn get_session_idle_timeout(&self) -> Duration {
+        self.session_idle_timeout
+    }
+
+    /// Gets the maximum number of concurrent sessions.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The session limit, or `None` when unbounded.
+    pub fn get_max_sessions(&self) -> Option<usize> {
+        self.max_sessions
+    }
+
+    /// Gets the number of receive sockets per bind address.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The worker count.
+    pub fn get_workers(&self) -> usize {
+        self.workers
+    }
+
+    /// Gets whether receive loops run on dedicated, core-pinned threads.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - The pinning setting.
+    pub fn get_pin_workers(&self) -> bool {
+        self.pin_workers
+    }
+
+    /// Gets the `TCP_NODELAY` option.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<bool>` - The `TCP_NODELAY` value if set.
+    pub fn get_nodelay(&self) -> Option<bool> {
+        self.nodelay
+    }
+
+    /// Gets the `IP_TTL` option.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u32>` - The `IP_TTL` value if set.
+    pub fn get_ttl(&self) -> Option<u32> {
+        self.ttl
+    }
+
+    /// Gets the `SO_REUSEADDR` option.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<bool>` - The `SO_REUSEADDR` value if set.
+    pub fn get_reuse_address(&self) -> Option<bool> {
+        self.reuse_address
+    }
+
+    /// Gets the `SO_REUSEPORT` option.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<bool>` - The `SO_REUSEPORT` value if set.
+    pub fn get_reuse_port(&self) -> Option<bool> {
+        self.reuse_port
+    }
+
+    /// Gets the `SO_RCVBUF` size.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The receive buffer size in bytes if set.
+    pub fn get_recv_buffer_size(&self) -> Option<usize> {
+        self.recv_buffer_size
+    }
+
+    /// Gets the `SO_SNDBUF` size.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The send buffer size in bytes if set.
+    pub fn get_send_buffer_size(&self) -> Option<usize> {
+        self.send_buffer_size
+    }
+
+    /// Gets the `IP_TOS` option.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u32>` - The type-of-service value if set.
+    pub fn get_tos(&self) -> Option<u32> {
+        self.tos
+    }
+
+    /// Gets the `IPV6_V6ONLY` option.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<bool>` - The `IPV6_V6ONLY` value if set.
+    pub fn get_only_v6(&self) -> Option<bool> {
+        self.only_v6
+    }
+
+    /// Gets the `SO_BROADCAST` option.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<bool>` - The `SO_BROADCAST` value if set.
+    pub fn get_broadcast(&self) -> Option<bool> {
+        self.broadcast
+    }
+
+    /// Sets the host address.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<str>` - The host address to set.
+    pub fn set_host<H>(&mut self, host: H)
+    where
+        H: AsRef<str>,
+    {
+        self.host = host.as_ref().to_owned();
+    }
+
+    /// Sets the port number.
+    ///
+    /// # Arguments
+    ///
+    /// - `u16` - The port number to set.
+    pub fn set_port(&mut self, port: u16) {
+        self.port = port;
+    }
+
+    /// Sets the additional listen addresses.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<String>` - The `host:port` addresses to listen on.
+    pub fn set_listen(&mut self, listen: Vec<String>) {
+        self.listen = listen;
+    }
+
+    /// Sets the buffer size.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The buffer size in bytes.
+    pub fn set_buffer_size(&mut self, size: usize) {
+        self.buffer_size = size;
+    }
+
+    /// Sets the maximum number of datagrams per system call.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The batch size; `0` is treated as `1`.
+    pub fn set_batch_size(&mut self, batch_size: usize) {
+        self.batch_size = batch_size.max(1);
+    }
+
+    /// Sets the truncation policy.
+    ///
+    /// # Arguments
+    ///
+    /// - `TruncationPolicy` - The policy applied to oversized datagrams.
+    pub fn set_truncation_policy(&mut self, policy: TruncationPolicy) {
+        self.truncation_policy = policy;
+    }
+
+    /// Sets the auto-flush setting.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether the accumulated response is sent automatically.
+    pub fn set_auto_flush(&mut self, auto_flush: bool) {
+        self.auto_flush = auto_flush;
+    }
+
+    /// Sets the `UDP_GRO` setting.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether GRO is enabled on the listening sockets.
+    pub fn set_gro(&mut self, gro: bool) {
+        self.gro = gro;
+    }
+
+    /// Sets the correlation ID framing setting.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether datagrams carry a correlation ID.
+    pub fn set_correlation_ids(&mut self, correlation_ids: bool) {
+        self.correlation_ids = correlation_ids;
+    }
+
+    /// Sets the maximum number of concurrently running request handlers.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<usize>` - The handler limit, or `None` for unbounded.
+    pub fn set_max_in_flight(&mut self, max: Option<usize>) {
+        self.max_in_flight = max;
+    }
+
+    /// Sets the policy applied when the handler limit is reached.
+    ///
+    /// # Arguments
+    ///
+    /// - `OverloadPolicy` - The overload policy.
+    pub fn set_overload_policy(&mut self, policy: OverloadPolicy) {
+        self.overload_policy = policy;
+    }
+
+    /// Sets the maximum number of concurrently running read error hook chains.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The read error hook limit.
+    pub fn set_max_read_error_in_flight(&mut self, max: usize) {
+        self.max_read_error_in_flight = max;
+    }
+
+    /// Sets the per-source rate limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<RateLimit>` - The rate limit, or `None` to disable it.
+    pub fn set_rate_limit(&mut self, rate_limit: Option<RateLimit>) {
+        self.rate_limit = rate_limit;
+    }
+
+    /// Sets the graceful shutdown drain deadline.
+    ///
+    /// # Arguments
+    ///
+    /// - `Duration` - The drain deadline.
+    pub fn set_shutdown_timeout(&mut self, timeout: Duration) {
+        self.shutdown_timeout = timeout;
+    }
+
+    /// Sets the deadline for the hook chain of each request.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<Duration>` - The per-request deadline, or `None` for unbounded.
+    pub fn set_request_timeout(&mut self, timeout: Option<Duration>) {
+        self.request_timeout = timeout;
+    }
+
+    /// Sets how long a session may stay idle before it expires.
+    ///
+    /// The timeout is at least `MIN_SESSION_IDLE_TIMEOUT`.
+    ///
+    /// # Arguments
+    ///
+    /// - `Duration` - The session idle timeout.
+    pub fn set_session_idle_timeout(&mut self, timeout: Duration) {
+        self.session_idle_timeout = timeout.max(MIN_SESSION_IDLE_TIMEOUT);
+    }
+
+    /// Sets the maximum number of concurrent sessions.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<usize>` - The session limit, or `None` for unbounded.
+    pub fn set_max_sessions(&mut self, max: Option<usize>) {
+        self.max_sessions = max;
+    }
+
+    /// Sets the number of receive sockets per bind address.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The worker count; values below `1` are treated as `1`.
+    pub fn set_workers(&mut self, workers: usize) {
+        self.workers = workers.max(1);
+    }
+
+    /// Sets whether receive loops run on dedicated, core-pinned threads.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - The pinning setting.
+    pub fn set_pin_workers(&mut self, pin_workers: bool) {
+        self.pin_workers = pin_workers;
+    }
+
+    /// Sets the `TCP_NODELAY` option.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<bool>` - The `TCP_NODELAY` value.
+    pub fn set_nodelay(&mut self, nodelay: Option<bool>) {
+        self.nodelay = nodelay;
+    }
+
+    /// Sets the `IP_TTL` option.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<u32>` - The `IP_TTL` value.
+    pub fn set_ttl(&mut self, ttl: Option<u32>) {
+        self.ttl = ttl;
+    }
+
+    /// Sets the `SO_REUSEADDR` option.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<bool>` - The `SO_REUSEADDR` value.
+    pub fn set_reuse_address(&mut self, reuse_address: Option<bool>) {
+        self.reuse_address = reuse_address;
+    }
+
+    /// Sets the `SO_REUSEPORT` option.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<bool>` - The `SO_REUSEPORT` value.
+    pub fn set_reuse_port(&mut self, reuse_port: Option<bool>) {
+        self.reuse_port = reuse_port;
+    }
+
+    /// Sets the `SO_RCVBUF` size.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<usize>` - The receive buffer size in bytes.
+    pub fn set_recv_buffer_size(&mut self, size: Option<usize>) {
+        self.recv_buffer_size = size;
+    }
 
-    /// Sets the `IP_TTL` option.
+    /// Sets the `SO_SNDBUF` size.
     ///
     /// # Arguments
     ///
-    /// - `Option<u32>` - The `IP_TTL` value.
-    pub fn set_ttl(&mut self, ttl: Option<u32>) {
-        self.ttl = ttl;
+    /// - `Option<usize>` - The send buffer size in bytes.
+    pub fn set_send_buffer_size(&mut self, size: Option<usize>) {
+        self.send_buffer_size = size;
+    }
+
+    /// Sets the `IP_TOS` option.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<u32>` - The type-of-service value.
+    pub fn set_tos(&mut self, tos: Option<u32>) {
+        self.tos = tos;
+    }
+
+    /// Sets the `IPV6_V6ONLY` option.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<bool>` - The `IPV6_V6ONLY` value.
+    pub fn set_only_v6(&mut self, only_v6: Option<bool>) {
+        self.only_v6 = only_v6;
+    }
+
+    /// Sets the `SO_BROADCAST` option.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<bool>` - The `SO_BROADCAST` value.
+    pub fn set_broadcast(&mut self, broadcast: Option<bool>) {
+        self.broadcast = broadcast;
+    }
+}
+
+/// Implementation of methods for `RateLimit`.
+impl RateLimit {
+    /// Creates a rate limit applied to each source IP address.
+    ///
+    /// # Arguments
+    ///
+    /// - `u32` - The sustained number of datagrams allowed per second.
+    /// - `u32` - The maximum number of datagrams allowed in a burst.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The rate limit.
+    pub fn new(requests_per_second: u32, burst: u32) -> Self {
+        Self {
+            requests_per_second,
+            burst,
+            ipv4_prefix: 32,
+            ipv6_prefix: 128,
+            max_hooks_in_flight: DEFAULT_RATE_LIMITED_IN_FLIGHT,
+            max_sources: DEFAULT_RATE_LIMIT_MAX_SOURCES,
+        }
+    }
+
+    /// Groups sources by subnet instead of by individual address.
+    ///
+    /// Prefix lengths are clamped to 32 and 128 bits.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The IPv4 prefix length, e.g. `24`.
+    /// - `u8` - The IPv6 prefix length, e.g. `64`.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The rate limit with the given prefixes.
+    pub fn prefix(mut self, ipv4_prefix: u8, ipv6_prefix: u8) -> Self {
+        self.ipv4_prefix = ipv4_prefix.min(32);
+        self.ipv6_prefix = ipv6_prefix.min(128);
+        self
+    }
+
+    /// Sets the maximum number of rate limited hook chains that may run at once.
+    ///
+    /// Rejected datagrams arriving while that many chains are running skip the
+    /// hooks and are counted by `ServerControlHook::rate_limited_hooks_dropped`.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The rate limited hook limit.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The rate limit with the given hook limit.
+    pub fn max_hooks_in_flight(mut self, max: usize) -> Self {
+        self.max_hooks_in_flight = max;
+        self
+    }
+
+    /// Sets the maximum number of sources tracked at once.
+    ///
+    /// Once the limit is reached and no idle source can be forgotten, datagrams
+    /// from new sources are rejected as rate limited. The limit is at least `1`.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The source limit.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The rate limit with the given source limit.
+    pub fn max_sources(mut self, max: usize) -> Self {
+        self.max_sources = max.max(1);
+        self
+    }
+
+    /// Gets the sustained number of datagrams allowed per second.
+    ///
+    /// # Returns
+    ///
+    /// - `u32` - The refill rate.
+    pub fn get_requests_per_second(&self) -> u32 {
+        self.requests_per_second
+    }
+
+    /// Gets the maximum number of datagrams allowed in a burst.
+    ///
+    /// # Returns
+    ///
+    /// - `u32` - The bucket capacity.
+    pub fn get_burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Gets the prefix length used to group IPv4 sources.
+    ///
+    /// # Returns
+    ///
+    /// - `u8` - The IPv4 prefix length.
+    pub fn get_ipv4_prefix(&self) -> u8 {
+        self.ipv4_prefix
+    }
+
+    /// Gets the prefix length used to group IPv6 sources.
+    ///
+    /// # Returns
+    ///
+    /// - `u8` - The IPv6 prefix length.
+    pub fn get_ipv6_prefix(&self) -> u8 {
+        self.ipv6_prefix
+    }
+
+    /// Gets the maximum number of rate limited hook chains running at once.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The rate limited hook limit.
+    pub fn get_max_hooks_in_flight(&self) -> usize {
+        self.max_hooks_in_flight
+    }
+
+    /// Gets the maximum number of sources tracked at once.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The source limit.
+    pub fn get_max_sources(&self) -> usize {
+        self.max_sources
+    }
+}
+
+/// Provides a default implementation for ClientConfigData.
+impl Default for ClientConfigData {
+    /// Creates a new ClientConfigData instance with default values.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new instance with default configuration.
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            bind: DEFAULT_CLIENT_BIND.to_owned(),
+            buffer_size: MAX_DATAGRAM_SIZE,
+            timeout: DEFAULT_CLIENT_TIMEOUT,
+            retries: DEFAULT_CLIENT_RETRIES,
+            correlation_ids: DEFAULT_CORRELATION_IDS,
+        }
+    }
+}
+
+/// Provides a default implementation for ClientConfig.
+impl Default for ClientConfig {
+    /// Creates a new ClientConfig instance with default values.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new instance wrapping default ClientConfigData.
+    #[inline(always)]
+    fn default() -> Self {
+        Self(arc_rwlock(ClientConfigData::default()))
+    }
+}
+
+/// Implementation of `From` trait for `ClientConfig`.
+impl From<ClientConfigData> for ClientConfig {
+    /// Creates a `ClientConfig` from a `ClientConfigData`.
+    ///
+    /// # Arguments
+    ///
+    /// - `ClientConfigData` - The configuration data.
+    ///
+    /// # Returns
+    ///
+    /// - `ClientConfig` - A `ClientConfig` instance.
+    #[inline(always)]
+    fn from(data: ClientConfigData) -> Self {
+        Self(arc_rwlock(data))
+    }
+}
+
+/// Implementation block for `ClientConfig`.
+impl ClientConfig {
+    /// Creates a new `ClientConfig` with default values.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `ClientConfig` instance.
+    pub async fn new() -> Self {
+        Self::default()
+    }
+
+    /// Acquires a read lock on the client configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `RwLockReadGuard<ClientConfigData>` - A read guard for the inner configuration.
+    async fn read(&self) -> RwLockReadGuard<'_, ClientConfigData> {
+        self.0.read().await
+    }
+
+    /// Acquires a write lock on the client configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `RwLockWriteGuard<ClientConfigData>` - A write guard for the inner configuration.
+    async fn write(&self) -> RwLockWriteGuard<'_, ClientConfigData> {
+        self.0.write().await
+    }
+
+    /// Retrieves a clone of the inner client configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `ClientConfigData` - A snapshot of the current client configuration.
+    pub async fn get_data(&self) -> ClientConfigData {
+        self.read().await.clone()
+    }
+
+    /// Sets the local address the client socket binds to.
+    ///
+    /// # Arguments
+    ///
+    /// - `A` - The `host:port` address to bind, e.g. `"127.0.0.1:0"`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn bind<A>(&self, addr: A) -> &Self
+    where
+        A: AsRef<str>,
+    {
+        self.write().await.bind = addr.as_ref().to_owned();
+        self
+    }
+
+    /// Sets the buffer size for receiving replies.
+    ///
+    /// # Arguments
+    ///
+    /// - `size` - The buffer size in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn buffer_size(&self, size: usize) -> &Self {
+        self.write().await.buffer_size = size.max(1);
+        self
+    }
+
+    /// Sets how long a request waits for each reply.
+    ///
+    /// # Arguments
+    ///
+    /// - `timeout` - The per-attempt deadline.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn timeout(&self, timeout: Duration) -> &Self {
+        self.write().await.timeout = timeout;
+        self
+    }
+
+    /// Sets how many times a request is resent after a timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `retries` - The number of additional attempts.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn retries(&self, retries: u32) -> &Self {
+        self.write().await.retries = retries;
+        self
+    }
+
+    /// Sets whether datagrams are framed with a correlation ID.
+    ///
+    /// Must match the server's setting. When enabled, every request carries a
+    /// fresh ID and replies are routed to the request with the same ID, so many
+    /// requests can be in flight over one client at once.
+    ///
+    /// # Arguments
+    ///
+    /// - `correlation_ids` - Whether to enable correlation ID framing.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to `Self` for method chaining.
+    pub async fn correlation_ids(&self, correlation_ids: bool) -> &Self {
+        self.write().await.correlation_ids = correlation_ids;
+        self
+    }
+}
+
+/// Implementation block for `ClientConfigData`.
+impl ClientConfigData {
+    /// Gets the local address the client socket binds to.
+    ///
+    /// # Returns
+    ///
+    /// - `&String` - Reference to the bind address.
+    pub fn get_bind(&self) -> &String {
+        &self.bind
+    }
+
+    /// Gets the buffer size for receiving replies.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The buffer size in bytes.
+    pub fn get_buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Gets how long a request waits for each reply.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The per-attempt deadline.
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Gets how many times a request is resent after a timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `u32` - The number of additional attempts.
+    pub fn get_retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// Gets the correlation ID framing setting.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether datagrams carry a correlation ID.
+    pub fn get_correlation_ids(&self) -> bool {
+        self.correlation_ids
+    }
+
+    /// Sets the local address the client socket binds to.
+    ///
+    /// # Arguments
+    ///
+    /// - `String` - The bind address.
+    pub fn set_bind(&mut self, bind: String) {
+        self.bind = bind;
+    }
+
+    /// Sets the buffer size for receiving replies.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The buffer size in bytes.
+    pub fn set_buffer_size(&mut self, buffer_size: usize) {
+        self.buffer_size = buffer_size.max(1);
+    }
+
+    /// Sets how long a request waits for each reply.
+    ///
+    /// # Arguments
+    ///
+    /// - `Duration` - The per-attempt deadline.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Sets how many times a request is resent after a timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `u32` - The number of additional attempts.
+    pub fn set_retries(&mut self, retries: u32) {
+        self.retries = retries;
+    }
+
+    /// Sets the correlation ID framing setting.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - Whether datagrams carry a correlation ID.
+    pub fn set_correlation_ids(&mut self, correlation_ids: bool) {
+        self.correlation_ids = correlation_ids;
     }
 }
@@ -1,6 +1,8 @@
+mod r#enum;
 mod r#impl;
 mod r#struct;
 
+pub use r#enum::*;
 pub use r#impl::*;
 pub use r#struct::*;
 
@@ -11,12 +11,58 @@ pub struct ServerConfigData {
     pub(super) host: String,
     /// The port number the server will listen on.
     pub(super) port: u16,
+    /// Additional `host:port` addresses to listen on; when non-empty they replace `host` and `port`.
+    pub(super) listen: Vec<String>,
     /// The buffer size for receiving UDP packets.
     pub(super) buffer_size: usize,
+    /// The maximum number of datagrams received or sent per system call.
+    pub(super) batch_size: usize,
+    /// The policy applied to datagrams larger than the receive buffer.
+    pub(super) truncation_policy: TruncationPolicy,
+    /// Whether the accumulated context response is sent after the hook chain.
+    pub(super) auto_flush: bool,
+    /// Whether the kernel may coalesce consecutive datagrams through `UDP_GRO`.
+    pub(super) gro: bool,
+    /// Whether datagrams are framed with a correlation ID that replies echo.
+    pub(super) correlation_ids: bool,
+    /// The maximum number of request handlers running at once; `None` means unbounded.
+    pub(super) max_in_flight: Option<usize>,
+    /// The policy applied when `max_in_flight` handlers are already running.
+    pub(super) overload_policy: OverloadPolicy,
+    /// The maximum number of read error hook chains running at once, apart from the handlers.
+    pub(super) max_read_error_in_flight: usize,
+    /// The per-source rate limit applied before handlers are spawned.
+    pub(super) rate_limit: Option<RateLimit>,
+    /// The number of `SO_REUSEPORT` sockets, each with its own receive loop, per bind address.
+    pub(super) workers: usize,
+    /// Whether each receive loop runs on its own thread with a current-thread runtime pinned to a core.
+    pub(super) pin_workers: bool,
+    /// How long a graceful shutdown waits for in-flight handlers before cancelling them.
+    pub(super) shutdown_timeout: Duration,
+    /// How long the hook chain of a request may run before it is cancelled; `None` means unbounded.
+    pub(super) request_timeout: Option<Duration>,
+    /// How long a session may stay idle before it expires.
+    pub(super) session_idle_timeout: Duration,
+    /// The maximum number of concurrent sessions; `None` means unbounded.
+    pub(super) max_sessions: Option<usize>,
     /// The `TCP_NODELAY` option for sockets (applied when applicable).
     pub(super) nodelay: Option<bool>,
     /// The `IP_TTL` option for sockets (applied when applicable).
     pub(super) ttl: Option<u32>,
+    /// The `SO_REUSEADDR` option for sockets.
+    pub(super) reuse_address: Option<bool>,
+    /// The `SO_REUSEPORT` option for sockets.
+    pub(super) reuse_port: Option<bool>,
+    /// The `SO_RCVBUF` kernel receive buffer size in bytes.
+    pub(super) recv_buffer_size: Option<usize>,
+    /// The `SO_SNDBUF` kernel send buffer size in bytes.
+    pub(super) send_buffer_size: Option<usize>,
+    /// The `IP_TOS` / `IPV6_TCLASS` value for outgoing packets.
+    pub(super) tos: Option<u32>,
+    /// The `IPV6_V6ONLY` option for IPv6 sockets.
+    pub(super) only_v6: Option<bool>,
+    /// The `SO_BROADCAST` option for sockets.
+    pub(super) broadcast: Option<bool>,
 }
 
 /// Represents the thread-safe, shareable server configuration.
@@ -25,3 +71,50 @@ pub struct ServerConfigData {
 /// to allow for safe concurrent access and modification of the server settings.
 #[derive(Clone, Debug)]
 pub struct ServerConfig(pub(super) ArcRwLock<ServerConfigData>);
+
+/// Represents the inner, mutable client configuration.
+///
+/// It is not intended to be used directly by end-users, but rather through the
+/// `ClientConfig` wrapper.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClientConfigData {
+    /// The local `host:port` address the client socket binds to.
+    pub(super) bind: String,
+    /// The buffer size for receiving replies.
+    pub(super) buffer_size: usize,
+    /// How long a request waits for a reply before retrying.
+    pub(super) timeout: Duration,
+    /// How many times a request is resent after a timeout.
+    pub(super) retries: u32,
+    /// Whether datagrams are framed with a correlation ID used to match replies.
+    pub(super) correlation_ids: bool,
+}
+
+/// Represents the thread-safe, shareable client configuration.
+///
+/// This structure wraps `ClientConfigData` in an `Arc<RwLock<ClientConfigData>>`
+/// so it can be built up with the same chained setters as `ServerConfig`.
+#[derive(Clone, Debug)]
+pub struct ClientConfig(pub(super) ArcRwLock<ClientConfigData>);
+
+/// A token bucket rate limit applied per source address or subnet.
+///
+/// Each source is allowed `burst` datagrams at once and refills at
+/// `requests_per_second`. Sources are grouped by masking their address to
+/// `ipv4_prefix` / `ipv6_prefix` bits. At most `max_sources` sources are
+/// tracked, so a flood of spoofed sources cannot grow memory without bound.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RateLimit {
+    /// The sustained number of datagrams allowed per second.
+    pub(super) requests_per_second: u32,
+    /// The maximum number of datagrams allowed in a burst.
+    pub(super) burst: u32,
+    /// The prefix length used to group IPv4 sources.
+    pub(super) ipv4_prefix: u8,
+    /// The prefix length used to group IPv6 sources.
+    pub(super) ipv6_prefix: u8,
+    /// The maximum number of rate limited hook chains running at once.
+    pub(super) max_hooks_in_flight: usize,
+    /// The maximum number of sources tracked at once.
+    pub(super) max_sources: usize,
+}
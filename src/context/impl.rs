@@ -11,11 +11,23 @@ impl Default for ContextData {
     fn default() -> Self {
         Self {
             aborted: false,
+            replied: false,
+            truncated: false,
             socket: None,
             request: Request::new(),
             response: Response::default(),
             client_addr: None,
+            local_addr: None,
             attributes: HashMap::new(),
+            panic: None,
+            request_error: None,
+            reply_batcher: None,
+            correlation_id: None,
+            codec: None,
+            decoded: None,
+            state: Arc::default(),
+            sessions: None,
+            session: None,
         }
     }
 }
@@ -46,17 +58,109 @@ impl ContextData {
     /// # Returns
     ///
     /// - `Self` - A new ContextData instance.
+    #[deprecated(note = "use `from_udp_socket` with the shared socket")]
     pub fn new(socket: ArcRwLockUdpSocket, request: Request, client_addr: SocketAddr) -> Self {
+        Self::from_udp_socket(socket.into(), request, client_addr)
+    }
+
+    /// Creates a new ContextData with the given shared socket, request, and client address.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcUdpSocket` - The network socket.
+    /// - `Request` - The request data.
+    /// - `SocketAddr` - The client's socket address.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new ContextData instance.
+    pub fn from_udp_socket(
+        socket: ArcUdpSocket,
+        request: Request,
+        client_addr: SocketAddr,
+    ) -> Self {
         Self {
             aborted: false,
+            replied: false,
+            truncated: false,
             socket: Some(socket),
             request,
             response: Response::default(),
             client_addr: Some(client_addr),
+            local_addr: None,
             attributes: HashMap::new(),
+            panic: None,
+            request_error: None,
+            reply_batcher: None,
+            correlation_id: None,
+            codec: None,
+            decoded: None,
+            state: Arc::default(),
+            sessions: None,
+            session: None,
         }
     }
 
+    /// Creates a new ContextData describing a receive failure on the given socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcUdpSocket` - The network socket.
+    /// - `RequestError` - The receive error.
+    /// - `Option<SocketAddr>` - The sender's address, if known.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new ContextData instance without request data.
+    pub fn from_read_error(
+        socket: ArcUdpSocket,
+        error: RequestError,
+        client_addr: Option<SocketAddr>,
+    ) -> Self {
+        Self {
+            socket: Some(socket),
+            client_addr,
+            request_error: Some(error),
+            ..Self::default()
+        }
+    }
+
+    /// Gets the reply batcher.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&ReplyBatcher>` - The batcher replies are queued on, if any.
+    pub(crate) fn get_reply_batcher(&self) -> Option<&ReplyBatcher> {
+        self.reply_batcher.as_ref()
+    }
+
+    /// Sets the reply batcher.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<ReplyBatcher>` - The batcher replies are queued on.
+    pub(crate) fn set_reply_batcher(&mut self, reply_batcher: Option<ReplyBatcher>) {
+        self.reply_batcher = reply_batcher;
+    }
+
+    /// Gets the correlation ID.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u32>` - The correlation ID echoed on replies, if framing is enabled.
+    pub fn get_correlation_id(&self) -> Option<u32> {
+        self.correlation_id
+    }
+
+    /// Sets the correlation ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<u32>` - The correlation ID echoed on replies.
+    pub(crate) fn set_correlation_id(&mut self, correlation_id: Option<u32>) {
+        self.correlation_id = correlation_id;
+    }
+
     /// Gets the aborted flag.
     ///
     /// # Returns
@@ -75,12 +179,58 @@ impl ContextData {
         self.aborted = aborted;
     }
 
+    /// Gets the replied flag.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - The replied flag.
+    pub fn get_replied(&self) -> bool {
+        self.replied
+    }
+
+    /// Sets the replied flag.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - The new value for the replied flag.
+    pub fn set_replied(&mut self, replied: bool) {
+        self.replied = replied;
+    }
+
+    /// Gets the truncated flag.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - The truncated flag.
+    pub fn get_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Sets the truncated flag.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - The new value for the truncated flag.
+    pub fn set_truncated(&mut self, truncated: bool) {
+        self.truncated = truncated;
+    }
+
     /// Gets the socket.
     ///
     /// # Returns
     ///
     /// - `Option<ArcRwLockUdpSocket>` - The socket if present.
+    #[deprecated(note = "use `get_udp_socket`, the socket needs no lock")]
     pub fn get_socket(&self) -> Option<ArcRwLockUdpSocket> {
+        self.socket.clone().map(ArcRwLockUdpSocket::from_udp_socket)
+    }
+
+    /// Gets the shared socket.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ArcUdpSocket>` - The socket if present.
+    pub fn get_udp_socket(&self) -> Option<ArcUdpSocket> {
         self.socket.clone()
     }
 
@@ -120,6 +270,24 @@ impl ContextData {
         self.client_addr
     }
 
+    /// Gets the local address.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SocketAddr>` - The local address if present.
+    pub fn get_local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+
+    /// Sets the local address.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<SocketAddr>` - The local address the datagram arrived on.
+    pub fn set_local_addr(&mut self, local_addr: Option<SocketAddr>) {
+        self.local_addr = local_addr;
+    }
+
     /// Gets the attributes.
     ///
     /// # Returns
@@ -137,6 +305,132 @@ impl ContextData {
     pub fn get_attributes_mut(&mut self) -> &mut ThreadSafeAttributeStore {
         &mut self.attributes
     }
+
+    /// Gets the captured panic data.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&PanicData>` - Reference to the panic data if a panic occurred.
+    pub fn get_panic(&self) -> Option<&PanicData> {
+        self.panic.as_ref()
+    }
+
+    /// Sets the captured panic data.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<PanicData>` - The panic data to store.
+    pub fn set_panic(&mut self, panic: Option<PanicData>) {
+        self.panic = panic;
+    }
+
+    /// Gets the receive error.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&RequestError>` - Reference to the receive error if present.
+    pub fn get_request_error(&self) -> Option<&RequestError> {
+        self.request_error.as_ref()
+    }
+
+    /// Sets the receive error.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<RequestError>` - The receive error to store.
+    pub fn set_request_error(&mut self, request_error: Option<RequestError>) {
+        self.request_error = request_error;
+    }
+
+    /// Gets the codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&SharedCodec>` - The codec attached to the server, if any.
+    pub(crate) fn get_codec(&self) -> Option<&SharedCodec> {
+        self.codec.as_ref()
+    }
+
+    /// Sets the codec.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<SharedCodec>` - The codec attached to the server.
+    pub(crate) fn set_codec(&mut self, codec: Option<SharedCodec>) {
+        self.codec = codec;
+    }
+
+    /// Gets the decoded request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&DecodedRequest>` - The request decoded by the codec, if any.
+    pub(crate) fn get_decoded(&self) -> Option<&DecodedRequest> {
+        self.decoded.as_ref()
+    }
+
+    /// Sets the decoded request.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<DecodedRequest>` - The request decoded by the codec.
+    pub(crate) fn set_decoded(&mut self, decoded: Option<DecodedRequest>) {
+        self.decoded = decoded;
+    }
+
+    /// Gets the shared state store.
+    ///
+    /// # Returns
+    ///
+    /// - `&SharedStateStore` - The typed application state registered on the server.
+    pub(crate) fn get_state(&self) -> &SharedStateStore {
+        &self.state
+    }
+
+    /// Sets the shared state store.
+    ///
+    /// # Arguments
+    ///
+    /// - `SharedStateStore` - The typed application state registered on the server.
+    pub(crate) fn set_state(&mut self, state: SharedStateStore) {
+        self.state = state;
+    }
+
+    /// Gets the session store.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&SessionStore>` - The sessions of the server run, if any.
+    pub(crate) fn get_sessions(&self) -> Option<&SessionStore> {
+        self.sessions.as_ref()
+    }
+
+    /// Sets the session store.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<SessionStore>` - The sessions of the server run.
+    pub(crate) fn set_sessions(&mut self, sessions: Option<SessionStore>) {
+        self.sessions = sessions;
+    }
+
+    /// Gets the session of the client.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&Session>` - The session, once looked up.
+    pub fn get_session(&self) -> Option<&Session> {
+        self.session.as_ref()
+    }
+
+    /// Sets the session of the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<Session>` - The session.
+    pub(crate) fn set_session(&mut self, session: Option<Session>) {
+        self.session = session;
+    }
 }
 
 /// Implementation of methods for Context.
@@ -145,21 +439,50 @@ impl Context {
     ///
     /// # Arguments
     ///
-    /// - `socket` - The network socket.
+    /// - `socket` - The network socket, as an `ArcUdpSocket` or `&ArcRwLockUdpSocket`.
     /// - `request` - The request data.
     /// - `client_addr` - The client's socket address.
     ///
     /// # Returns
     ///
     /// - `Self` - A new Context instance.
-    pub fn new(socket: &ArcRwLockUdpSocket, request: &Request, client_addr: SocketAddr) -> Self {
-        Self(arc_rwlock(ContextData::new(
-            socket.clone(),
+    pub fn new<S>(socket: S, request: &Request, client_addr: SocketAddr) -> Self
+    where
+        S: Into<ArcUdpSocket>,
+    {
+        Self(arc_rwlock(ContextData::from_udp_socket(
+            socket.into(),
             request.clone(),
             client_addr,
         )))
     }
 
+    /// Creates a new Context describing a receive failure on the given socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `socket` - The network socket, as an `ArcUdpSocket` or `&ArcRwLockUdpSocket`.
+    /// - `error` - The receive error.
+    /// - `client_addr` - The sender's address, if known.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new Context instance.
+    pub fn from_read_error<S>(
+        socket: S,
+        error: RequestError,
+        client_addr: Option<SocketAddr>,
+    ) -> Self
+    where
+        S: Into<ArcUdpSocket>,
+    {
+        Self(arc_rwlock(ContextData::from_read_error(
+            socket.into(),
+            error,
+            client_addr,
+        )))
+    }
+
     /// Acquires a read lock on the inner context data.
     ///
     /// # Returns
@@ -206,13 +529,36 @@ impl Context {
         self.set_aborted(false).await;
     }
 
+    /// Checks if the request data was truncated to fit the receive buffer.
+    ///
+    /// Only set when the server uses `TruncationPolicy::Deliver`.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if the request data is truncated, otherwise false.
+    pub async fn get_truncated(&self) -> bool {
+        self.read().await.get_truncated()
+    }
+
+    /// Sets the truncated flag for the context.
+    ///
+    /// # Arguments
+    ///
+    /// - `truncated` - The truncated state to set.
+    pub(crate) async fn set_truncated(&self, truncated: bool) {
+        self.write().await.set_truncated(truncated);
+    }
+
     /// Retrieves the underlying network socket, if available.
     ///
     /// # Returns
     ///
     /// - `Option<ArcRwLockUdpSocket>` - The thread-safe, shareable network socket if it exists.
+    #[deprecated(note = "use `try_get_udp_socket`, the socket needs no lock")]
     pub async fn try_get_socket(&self) -> Option<ArcRwLockUdpSocket> {
-        self.read().await.get_socket()
+        self.try_get_udp_socket()
+            .await
+            .map(ArcRwLockUdpSocket::from_udp_socket)
     }
 
     /// Retrieves the underlying network socket.
@@ -224,19 +570,63 @@ impl Context {
     /// # Panics
     ///
     /// - If the network socket is not found.
+    #[deprecated(note = "use `get_udp_socket`, the socket needs no lock")]
     pub async fn get_socket(&self) -> ArcRwLockUdpSocket {
-        self.try_get_socket().await.unwrap()
+        ArcRwLockUdpSocket::from_udp_socket(self.get_udp_socket().await)
+    }
+
+    /// Retrieves the shared network socket, if available.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ArcUdpSocket>` - The shared network socket if it exists.
+    pub async fn try_get_udp_socket(&self) -> Option<ArcUdpSocket> {
+        self.read().await.get_udp_socket()
+    }
+
+    /// Retrieves the shared network socket.
+    ///
+    /// # Returns
+    ///
+    /// - `ArcUdpSocket` - The shared network socket.
+    ///
+    /// # Panics
+    ///
+    /// - If the network socket is not found.
+    pub async fn get_udp_socket(&self) -> ArcUdpSocket {
+        self.try_get_udp_socket().await.unwrap()
     }
 
     /// Retrieves the current request.
     ///
+    /// The returned handle shares the received buffer; the payload is not copied.
+    /// See `get_request_ref` for how long that buffer stays allocated.
+    ///
     /// # Returns
     ///
-    /// - `Request` - A clone of the current request.
+    /// - `Request` - A shared handle to the current request.
     pub async fn get_request(&self) -> Request {
         self.read().await.get_request().clone()
     }
 
+    /// Borrows the current request without cloning it.
+    ///
+    /// The context stays read-locked while the guard is held, so drop it before
+    /// calling methods that modify the context.
+    ///
+    /// Requests are cut from a receive slab shared with neighbouring datagrams,
+    /// and the slab is only freed once every request cut from it is dropped. A
+    /// request kept alive after its handler finishes, through this guard's
+    /// target or a clone of it, pins the whole slab; copy the bytes out with
+    /// `to_vec` to keep them longer.
+    ///
+    /// # Returns
+    ///
+    /// - `RwLockReadGuard<'_, Request>` - A guard dereferencing to the current request.
+    pub async fn get_request_ref(&self) -> RwLockReadGuard<'_, Request> {
+        RwLockReadGuard::map(self.read().await, |data: &ContextData| data.get_request())
+    }
+
     /// Retrieves the current response.
     ///
     /// # Returns
@@ -246,6 +636,49 @@ impl Context {
         self.read().await.get_response().clone()
     }
 
+    /// Replaces the accumulated response.
+    ///
+    /// # Arguments
+    ///
+    /// - `Into<ResponseData>` - The response data.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn set_response<T>(&self, data: T) -> &Self
+    where
+        T: Into<ResponseData>,
+    {
+        self.write().await.get_response_mut().set_data(data);
+        self
+    }
+
+    /// Appends data to the accumulated response.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The data to append.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn append_response<T>(&self, data: T) -> &Self
+    where
+        T: AsRef<[u8]>,
+    {
+        self.write().await.get_response_mut().append(data);
+        self
+    }
+
+    /// Checks whether a reply has already been sent for this request.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True if `send`, `send_segmented` or `send_response` succeeded.
+    pub async fn get_replied(&self) -> bool {
+        self.read().await.get_replied()
+    }
+
     /// Retrieves the client address, if available.
     ///
     /// # Returns
@@ -297,6 +730,77 @@ impl Context {
         self.try_get_client_addr().await.map(|addr| addr.port())
     }
 
+    /// Retrieves the local address the datagram arrived on, if available.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SocketAddr>` - The local address if present.
+    pub async fn try_get_local_addr(&self) -> Option<SocketAddr> {
+        self.read().await.get_local_addr()
+    }
+
+    /// Retrieves the local address the datagram arrived on.
+    ///
+    /// # Returns
+    ///
+    /// - `SocketAddr` - The local address.
+    ///
+    /// # Panics
+    ///
+    /// - If the local address is not found.
+    pub async fn get_local_addr(&self) -> SocketAddr {
+        self.try_get_local_addr().await.unwrap()
+    }
+
+    /// Sets the batcher replies are queued on.
+    ///
+    /// # Arguments
+    ///
+    /// - `reply_batcher` - The reply batcher, or `None` to send replies directly.
+    pub(crate) async fn set_reply_batcher(&self, reply_batcher: Option<ReplyBatcher>) {
+        self.write().await.set_reply_batcher(reply_batcher);
+    }
+
+    /// Retrieves the correlation ID of the request, if framing is enabled.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u32>` - The correlation ID echoed on replies.
+    pub async fn try_get_correlation_id(&self) -> Option<u32> {
+        self.read().await.get_correlation_id()
+    }
+
+    /// Retrieves the correlation ID of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `u32` - The correlation ID echoed on replies.
+    ///
+    /// # Panics
+    ///
+    /// - If correlation ID framing is not enabled.
+    pub async fn get_correlation_id(&self) -> u32 {
+        self.try_get_correlation_id().await.unwrap()
+    }
+
+    /// Sets the correlation ID echoed on every reply.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<u32>` - The correlation ID, or `None` to send replies unframed.
+    pub(crate) async fn set_correlation_id(&self, correlation_id: Option<u32>) {
+        self.write().await.set_correlation_id(correlation_id);
+    }
+
+    /// Sets the local address the datagram arrived on.
+    ///
+    /// # Arguments
+    ///
+    /// - `local_addr` - The local address.
+    pub(crate) async fn set_local_addr(&self, local_addr: Option<SocketAddr>) {
+        self.write().await.set_local_addr(local_addr);
+    }
+
     /// Sets an attribute in the context.
     ///
     /// # Arguments
@@ -389,8 +893,419 @@ impl Context {
         self
     }
 
+    /// Retrieves the panic data captured while processing the request, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<PanicData>` - The panic data if a handler panicked.
+    pub async fn try_get_panic_data(&self) -> Option<PanicData> {
+        self.read().await.get_panic().cloned()
+    }
+
+    /// Retrieves the panic data captured while processing the request.
+    ///
+    /// # Returns
+    ///
+    /// - `PanicData` - The panic data.
+    ///
+    /// # Panics
+    ///
+    /// - If no panic has been captured.
+    pub async fn get_panic_data(&self) -> PanicData {
+        self.try_get_panic_data().await.unwrap()
+    }
+
+    /// Retrieves the receive error that produced this context, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RequestError>` - The receive error if present.
+    pub async fn try_get_request_error(&self) -> Option<RequestError> {
+        self.read().await.get_request_error().cloned()
+    }
+
+    /// Retrieves the receive error that produced this context.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The receive error.
+    ///
+    /// # Panics
+    ///
+    /// - If the context was not created for a receive error.
+    pub async fn get_request_error(&self) -> RequestError {
+        self.try_get_request_error().await.unwrap()
+    }
+
+    /// Retrieves the receive error as a server-level error, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ServerError>` - The receive error wrapped as `ServerError::UdpRead`.
+    pub async fn try_get_server_error(&self) -> Option<ServerError> {
+        self.try_get_request_error().await.map(ServerError::from)
+    }
+
+    /// Stores the error that stopped the request from reaching the hooks.
+    ///
+    /// # Arguments
+    ///
+    /// - `RequestError` - The request error.
+    pub(crate) async fn set_request_error(&self, request_error: RequestError) {
+        self.write().await.set_request_error(Some(request_error));
+    }
+
+    /// Clears the flags left behind by a previous run of the hook chain.
+    ///
+    /// Resets the aborted and replied flags and the request error, so a chain
+    /// run again by a middleware starts from the same state as the first run.
+    pub(crate) async fn reset_run(&self) {
+        let mut context: RwLockWriteGuard<'_, ContextData> = self.write().await;
+        context.set_aborted(false);
+        context.set_replied(false);
+        context.set_request_error(None);
+    }
+
+    /// Sets the typed application state registered on the server.
+    ///
+    /// # Arguments
+    ///
+    /// - `SharedStateStore` - The shared state store.
+    pub(crate) async fn set_state(&self, state: SharedStateStore) {
+        self.write().await.set_state(state);
+    }
+
+    /// Attempts to retrieve a value of shared application state by its type.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<T>` - A clone of the value registered with `Server::state`, if any.
+    pub async fn try_get_state<T>(&self) -> Option<T>
+    where
+        T: AnySendSyncClone,
+    {
+        self.read()
+            .await
+            .get_state()
+            .get(&TypeId::of::<T>())
+            .and_then(|arc| arc.downcast_ref::<T>())
+            .cloned()
+    }
+
+    /// Retrieves a value of shared application state by its type.
+    ///
+    /// # Returns
+    ///
+    /// - `T` - A clone of the value registered with `Server::state`.
+    ///
+    /// # Panics
+    ///
+    /// - If no value of type `T` was registered.
+    pub async fn get_state<T>(&self) -> T
+    where
+        T: AnySendSyncClone,
+    {
+        self.try_get_state().await.unwrap()
+    }
+
+    /// Sets the sessions of the server run.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<SessionStore>` - The session store.
+    pub(crate) async fn set_sessions(&self, sessions: Option<SessionStore>) {
+        self.write().await.set_sessions(sessions);
+    }
+
+    /// Sets the session of the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `Session` - The session.
+    pub(crate) async fn set_session(&self, session: Session) {
+        self.write().await.set_session(Some(session));
+    }
+
+    /// Attempts to retrieve the session of the client, creating it on first use.
+    ///
+    /// A new session runs the session created hooks before it is returned, and
+    /// idle sessions evicted to make room run the session expired hooks. The
+    /// session hooks run on a context of their own, so aborting either that
+    /// context or this one does not affect the other.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Session>` - The session, or `None` when the context does not belong
+    ///   to a running server or the session limit is reached.
+    pub async fn try_get_session(&self) -> Option<Session> {
+        let (session, sessions, client_addr, socket): (
+            Option<Session>,
+            Option<SessionStore>,
+            Option<SocketAddr>,
+            Option<ArcUdpSocket>,
+        ) = {
+            let context: RwLockReadGuard<'_, ContextData> = self.read().await;
+            (
+                context.get_session().cloned(),
+                context.get_sessions().cloned(),
+                context.get_client_addr(),
+                context.get_udp_socket(),
+            )
+        };
+        if session.is_some() {
+            return session;
+        }
+        let sessions: SessionStore = sessions?;
+        let lookup: SessionLookup = sessions.get_or_create(client_addr?, socket?);
+        expire_sessions(&sessions, lookup.expired);
+        let session: Session = lookup.session?;
+        self.set_session(session.clone()).await;
+        if lookup.created && !sessions.get_created().is_empty() {
+            let ctx: Context = sessions.session_context(session.clone()).await;
+            for hook in sessions.get_created().iter() {
+                hook(&ctx).await;
+                if ctx.get_aborted().await {
+                    break;
+                }
+            }
+        }
+        Some(session)
+    }
+
+    /// Retrieves the session of the client, creating it on first use.
+    ///
+    /// Same as `try_get_session`.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Session>` - The session, or `None` when the context does not belong
+    ///   to a running server or the session limit is reached.
+    pub async fn session(&self) -> Option<Session> {
+        self.try_get_session().await
+    }
+
+    /// Retrieves the session of the client, creating it on first use.
+    ///
+    /// # Returns
+    ///
+    /// - `Session` - The session.
+    ///
+    /// # Panics
+    ///
+    /// - If the context does not belong to a running server or the session limit is reached.
+    pub async fn get_session(&self) -> Session {
+        self.try_get_session().await.unwrap()
+    }
+
+    /// Ends the session of the client without running the session expired hooks.
+    ///
+    /// The next call to `try_get_session` creates a new session.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Session>` - The removed session, if any.
+    pub async fn remove_session(&self) -> Option<Session> {
+        let (sessions, client_addr): (Option<SessionStore>, Option<SocketAddr>) = {
+            let mut context: RwLockWriteGuard<'_, ContextData> = self.write().await;
+            context.set_session(None);
+            (context.get_sessions().cloned(), context.get_client_addr())
+        };
+        sessions?.remove(client_addr?)
+    }
+
+    /// Sets the codec used to encode replies.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<SharedCodec>` - The codec attached to the server.
+    pub(crate) async fn set_codec(&self, codec: Option<SharedCodec>) {
+        self.write().await.set_codec(codec);
+    }
+
+    /// Stores the request decoded by the server's codec.
+    ///
+    /// # Arguments
+    ///
+    /// - `DecodedRequest` - The decoded request.
+    pub(crate) async fn set_decoded(&self, decoded: DecodedRequest) {
+        self.write().await.set_decoded(Some(decoded));
+    }
+
+    /// Attempts to retrieve the request decoded by the server's codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<T>>` - The decoded request if a codec is attached and its
+    ///   request type is `T`.
+    pub async fn try_get_decoded<T>(&self) -> Option<Arc<T>>
+    where
+        T: Any + Send + Sync,
+    {
+        self.read()
+            .await
+            .get_decoded()
+            .cloned()
+            .and_then(|decoded: DecodedRequest| decoded.downcast::<T>().ok())
+    }
+
+    /// Retrieves the request decoded by the server's codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<T>` - The decoded request.
+    ///
+    /// # Panics
+    ///
+    /// - If no codec is attached or its request type is not `T`.
+    pub async fn get_decoded<T>(&self) -> Arc<T>
+    where
+        T: Any + Send + Sync,
+    {
+        self.try_get_decoded().await.unwrap()
+    }
+
+    /// Encodes a typed response with the server's codec and sends it to the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The response, which must be the codec's response type.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the send operation, or `ResponseError::EncodeError`
+    ///   when no codec is attached or encoding fails.
+    pub async fn send_encoded<T>(&self, response: &T) -> ResponseResult
+    where
+        T: Any,
+    {
+        let codec: SharedCodec = self.read().await.get_codec().cloned().ok_or_else(|| {
+            ResponseError::EncodeError("no codec is attached to the server".to_owned())
+        })?;
+        let data: ResponseData = codec.encode_erased(response)?;
+        self.send(data).await
+    }
+
+    /// Deserializes the request payload as JSON.
+    ///
+    /// This does not require a codec to be attached to the server.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, RequestError>` - The deserialized request, or
+    ///   `RequestError::DecodeError` when the payload is not valid JSON for `T`.
+    #[cfg(feature = "json")]
+    pub async fn get_json<T>(&self) -> Result<T, RequestError>
+    where
+        T: DeserializeOwned,
+    {
+        decode_json(self.read().await.get_request()).map_err(RequestError::DecodeError)
+    }
+
+    /// Serializes a value as JSON and sends it to the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The value to send.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the send operation, or `ResponseError::EncodeError`
+    ///   when serialization fails.
+    #[cfg(feature = "json")]
+    pub async fn send_json<T>(&self, value: &T) -> ResponseResult
+    where
+        T: Serialize + ?Sized,
+    {
+        let data: ResponseData = encode_json(value).map_err(ResponseError::EncodeError)?;
+        self.send(data).await
+    }
+
+    /// Deserializes the request payload as CBOR.
+    ///
+    /// This does not require a codec to be attached to the server.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, RequestError>` - The deserialized request, or
+    ///   `RequestError::DecodeError` when the payload is not valid CBOR for `T`.
+    #[cfg(feature = "cbor")]
+    pub async fn get_cbor<T>(&self) -> Result<T, RequestError>
+    where
+        T: DeserializeOwned,
+    {
+        decode_cbor(self.read().await.get_request()).map_err(RequestError::DecodeError)
+    }
+
+    /// Serializes a value as CBOR and sends it to the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The value to send.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the send operation, or `ResponseError::EncodeError`
+    ///   when serialization fails.
+    #[cfg(feature = "cbor")]
+    pub async fn send_cbor<T>(&self, value: &T) -> ResponseResult
+    where
+        T: Serialize + ?Sized,
+    {
+        let data: ResponseData = encode_cbor(value).map_err(ResponseError::EncodeError)?;
+        self.send(data).await
+    }
+
+    /// Deserializes the request payload as MessagePack.
+    ///
+    /// This does not require a codec to be attached to the server.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, RequestError>` - The deserialized request, or
+    ///   `RequestError::DecodeError` when the payload is not valid MessagePack for `T`.
+    #[cfg(feature = "msgpack")]
+    pub async fn get_msgpack<T>(&self) -> Result<T, RequestError>
+    where
+        T: DeserializeOwned,
+    {
+        decode_msgpack(self.read().await.get_request()).map_err(RequestError::DecodeError)
+    }
+
+    /// Serializes a value as MessagePack and sends it to the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The value to send.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the send operation, or `ResponseError::EncodeError`
+    ///   when serialization fails.
+    #[cfg(feature = "msgpack")]
+    pub async fn send_msgpack<T>(&self, value: &T) -> ResponseResult
+    where
+        T: Serialize + ?Sized,
+    {
+        let data: ResponseData = encode_msgpack(value).map_err(ResponseError::EncodeError)?;
+        self.send(data).await
+    }
+
+    /// Stores the panic data captured while processing the request.
+    ///
+    /// # Arguments
+    ///
+    /// - `PanicData` - The panic data to store.
+    pub(crate) async fn set_panic_data(&self, panic: PanicData) {
+        self.write().await.set_panic(Some(panic));
+    }
+
     /// Sends a response to the client.
     ///
+    /// The reply is sent from the local address the request arrived on when known.
+    /// When the server batches replies, it is queued and this waits until the batch
+    /// containing it has been sent. With correlation ID framing enabled, the
+    /// request's ID is prepended to the reply.
+    ///
     /// # Arguments
     ///
     /// - `Into<ResponseData>` - The response data.
@@ -402,9 +1317,108 @@ impl Context {
     where
         T: Into<ResponseData>,
     {
-        let socket_opt: Option<ArcRwLockUdpSocket> = self.try_get_socket().await;
-        let addr_opt: Option<SocketAddr> = self.try_get_client_addr().await;
-        let response: Response = Response::from(data);
-        response.send(&socket_opt, &addr_opt).await
+        let (socket_opt, addr_opt, local_addr_opt, reply_batcher): (
+            Option<ArcUdpSocket>,
+            Option<SocketAddr>,
+            Option<SocketAddr>,
+            Option<ReplyBatcher>,
+        ) = {
+            let context: RwLockReadGuard<'_, ContextData> = self.read().await;
+            (
+                context.get_udp_socket(),
+                context.get_client_addr(),
+                context.get_local_addr(),
+                context.get_reply_batcher().cloned(),
+            )
+        };
+        let data: ResponseData = data.into();
+        let response: Response = match self.try_get_correlation_id().await {
+            Some(id) => Response::from(frame_correlation_id(id, &data)),
+            None => Response::from(data),
+        };
+        match (reply_batcher, addr_opt) {
+            (Some(reply_batcher), Some(addr)) => {
+                let source_ip: Option<IpAddr> =
+                    local_addr_opt.map(|local_addr: SocketAddr| local_addr.ip());
+                reply_batcher
+                    .send(response.get_data().clone(), addr, source_ip)
+                    .await
+                    .map_err(|error: std::io::Error| ResponseError::SendError(error.to_string()))?;
+            }
+            _ => {
+                response
+                    .send_from(&socket_opt, &addr_opt, &local_addr_opt)
+                    .await?
+            }
+        }
+        self.write().await.set_replied(true);
+        Ok(())
+    }
+
+    /// Sends a payload to the client as a run of equally sized datagrams.
+    ///
+    /// The payload is split every `segment_size` bytes; only the last datagram may
+    /// be shorter. On Linux the datagrams are handed to the kernel with `UDP_SEGMENT`
+    /// (GSO) in as few system calls as possible, falling back to one send per
+    /// datagram when the kernel rejects segmentation. The reply batcher is bypassed.
+    /// With correlation ID framing enabled, every datagram carries the request's ID
+    /// in front of its `segment_size` bytes of payload.
+    ///
+    /// # Arguments
+    ///
+    /// - `Into<ResponseData>` - The payload to split.
+    /// - `usize` - The size of each datagram.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the send operation.
+    pub async fn send_segmented<T>(&self, data: T, segment_size: usize) -> ResponseResult
+    where
+        T: Into<ResponseData>,
+    {
+        let (socket_opt, addr_opt, local_addr_opt, correlation_id): (
+            Option<ArcUdpSocket>,
+            Option<SocketAddr>,
+            Option<SocketAddr>,
+            Option<u32>,
+        ) = {
+            let context: RwLockReadGuard<'_, ContextData> = self.read().await;
+            (
+                context.get_udp_socket(),
+                context.get_client_addr(),
+                context.get_local_addr(),
+                context.get_correlation_id(),
+            )
+        };
+        let socket: ArcUdpSocket = socket_opt.ok_or(ResponseError::SocketNotAvailable)?;
+        let addr: SocketAddr = addr_opt.ok_or(ResponseError::AddressNotAvailable)?;
+        let data: ResponseData = data.into();
+        let (data, segment_size): (ResponseData, usize) = match correlation_id {
+            Some(id) if segment_size > 0 => (
+                frame_correlation_id_segments(id, &data, segment_size),
+                segment_size + CORRELATION_ID_LEN,
+            ),
+            _ => (data, segment_size),
+        };
+        let source_ip: Option<IpAddr> =
+            local_addr_opt.map(|local_addr: SocketAddr| local_addr.ip());
+        send_segmented_datagram(&socket, &data, segment_size, addr, source_ip)
+            .await
+            .map_err(|error: std::io::Error| ResponseError::SendError(error.to_string()))?;
+        self.write().await.set_replied(true);
+        Ok(())
+    }
+
+    /// Sends the accumulated response to the client.
+    ///
+    /// Unlike the auto-flush run after the hook chain, this always sends, even when
+    /// the response is empty, the context was aborted or a reply was already sent.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the send operation.
+    pub async fn send_response(&self) -> ResponseResult {
+        let response: Response = self.get_response().await;
+        self.send(response.get_data().clone()).await
     }
 }
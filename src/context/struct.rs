@@ -9,15 +9,39 @@ pub struct ContextData {
     /// A flag indicating whether the request handling has been aborted.
     pub(super) aborted: bool,
     /// The underlying network socket for the connection.
-    pub(super) socket: Option<ArcRwLockUdpSocket>,
+    pub(super) socket: Option<ArcUdpSocket>,
+    /// Whether a reply has already been sent for this request.
+    pub(super) replied: bool,
+    /// Whether the request data was truncated to fit the receive buffer.
+    pub(super) truncated: bool,
     /// The incoming UDP request data.
     pub(super) request: Request,
     /// The outgoing UDP response data.
     pub(super) response: Response,
     /// The client's socket address.
     pub(super) client_addr: Option<SocketAddr>,
+    /// The local address the datagram arrived on.
+    pub(super) local_addr: Option<SocketAddr>,
     /// A collection of custom attributes for sharing data within the request lifecycle.
     pub(super) attributes: ThreadSafeAttributeStore,
+    /// The panic captured while processing the request, if any.
+    pub(super) panic: Option<PanicData>,
+    /// The receive error that produced this context, if any.
+    pub(super) request_error: Option<RequestError>,
+    /// The batcher replies are queued on when batching is enabled.
+    pub(super) reply_batcher: Option<ReplyBatcher>,
+    /// The correlation ID echoed on every reply, if framing is enabled.
+    pub(super) correlation_id: Option<u32>,
+    /// The codec used to decode the request and encode replies, if any.
+    pub(super) codec: Option<SharedCodec>,
+    /// The request decoded by the server's codec, if any.
+    pub(super) decoded: Option<DecodedRequest>,
+    /// The typed application state registered on the server.
+    pub(super) state: SharedStateStore,
+    /// The sessions of the server run, if the context belongs to one.
+    pub(super) sessions: Option<SessionStore>,
+    /// The session of the client, once looked up.
+    pub(super) session: Option<Session>,
 }
 
 /// The main application context, providing thread-safe access to request and response data.
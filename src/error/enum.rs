@@ -1,3 +1,5 @@
+use super::*;
+
 /// Represents errors that can occur at the server level.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ServerError {
@@ -7,6 +9,8 @@ pub enum ServerError {
     Unknown(String),
     /// An error occurred while reading a UDP request.
     UdpRead(String),
+    /// A setting targeted a route that has no registered handler.
+    UnknownRoute(String),
     /// Other error.
     Other(String),
 }
@@ -20,6 +24,14 @@ pub enum ResponseError {
     SocketNotAvailable,
     /// The address is not available.
     AddressNotAvailable,
+    /// An error occurred while binding the client socket.
+    BindError(String),
+    /// An error occurred while receiving a reply.
+    ReceiveError(String),
+    /// No reply arrived before the deadline.
+    Timeout,
+    /// The response could not be encoded by the codec.
+    EncodeError(String),
     /// An unknown or unexpected error occurred.
     Unknown,
 }
@@ -31,6 +43,16 @@ pub enum RequestError {
     ReadError(String),
     /// The request buffer is too small.
     BufferTooSmall,
+    /// The peer reset the connection (e.g. `WSAECONNRESET` after an ICMP error).
+    ConnectionReset(String),
+    /// The peer refused the connection (e.g. ICMP port unreachable).
+    ConnectionRefused(String),
+    /// The datagram is too short to carry a correlation ID.
+    MissingCorrelationId,
+    /// The request could not be decoded by the server's codec.
+    DecodeError(String),
+    /// The hook chain did not finish before the request deadline.
+    Timeout(Duration),
     /// An unknown or unexpected error occurred.
     Unknown,
 }
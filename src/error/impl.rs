@@ -19,6 +19,7 @@ impl Display for ServerError {
             Self::UdpBind(data) => write!(f, "UDP bind error: {data}"),
             Self::Unknown(data) => write!(f, "Unknown error: {data}"),
             Self::UdpRead(data) => write!(f, "UDP read error: {data}"),
+            Self::UnknownRoute(data) => write!(f, "Unknown route: {data}"),
             Self::Other(data) => write!(f, "Other error: {data}"),
         }
     }
@@ -43,6 +44,10 @@ impl Display for ResponseError {
             Self::SendError(data) => write!(f, "Send error: {data}"),
             Self::SocketNotAvailable => write!(f, "Socket not available"),
             Self::AddressNotAvailable => write!(f, "Address not available"),
+            Self::BindError(data) => write!(f, "Bind error: {data}"),
+            Self::ReceiveError(data) => write!(f, "Receive error: {data}"),
+            Self::Timeout => write!(f, "Timed out waiting for a reply"),
+            Self::EncodeError(data) => write!(f, "Encode error: {data}"),
             Self::Unknown => write!(f, "Unknown response error"),
         }
     }
@@ -66,7 +71,48 @@ impl Display for RequestError {
         match self {
             Self::ReadError(data) => write!(f, "Read error: {data}"),
             Self::BufferTooSmall => write!(f, "Buffer too small"),
+            Self::ConnectionReset(data) => write!(f, "Connection reset: {data}"),
+            Self::ConnectionRefused(data) => write!(f, "Connection refused: {data}"),
+            Self::MissingCorrelationId => write!(f, "Missing correlation ID"),
+            Self::DecodeError(data) => write!(f, "Decode error: {data}"),
+            Self::Timeout(data) => write!(f, "Request timed out after {}ms", data.as_millis()),
             Self::Unknown => write!(f, "Unknown request error"),
         }
     }
 }
+
+/// Conversion from an I/O error into a RequestError.
+impl From<std::io::Error> for RequestError {
+    /// Classifies a receive failure into a RequestError.
+    ///
+    /// # Arguments
+    ///
+    /// - `std::io::Error` - The I/O error returned by the socket.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The classified request error.
+    fn from(error: std::io::Error) -> Self {
+        match error.kind() {
+            ErrorKind::ConnectionReset => Self::ConnectionReset(error.to_string()),
+            ErrorKind::ConnectionRefused => Self::ConnectionRefused(error.to_string()),
+            _ => Self::ReadError(error.to_string()),
+        }
+    }
+}
+
+/// Conversion from a RequestError into a ServerError.
+impl From<RequestError> for ServerError {
+    /// Wraps a request error as a server-level UDP read error.
+    ///
+    /// # Arguments
+    ///
+    /// - `RequestError` - The request error.
+    ///
+    /// # Returns
+    ///
+    /// - `ServerError` - The corresponding `ServerError::UdpRead`.
+    fn from(error: RequestError) -> Self {
+        Self::UdpRead(error.to_string())
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+/// Splits the correlation ID off the front of a framed datagram.
+///
+/// # Arguments
+///
+/// - `&Request` - The framed datagram.
+///
+/// # Returns
+///
+/// - `Option<(u32, Request)>` - The correlation ID and the payload sharing the
+///   same buffer, or `None` if the datagram is too short to carry an ID.
+pub(crate) fn split_correlation_id(data: &Request) -> Option<(u32, Request)> {
+    let id: [u8; CORRELATION_ID_LEN] = data.get(..CORRELATION_ID_LEN)?.try_into().ok()?;
+    Some((u32::from_be_bytes(id), data.slice(CORRELATION_ID_LEN..)))
+}
+
+/// Prefixes a payload with a correlation ID.
+///
+/// # Arguments
+///
+/// - `u32` - The correlation ID.
+/// - `&[u8]` - The payload.
+///
+/// # Returns
+///
+/// - `ResponseData` - The framed datagram.
+pub(crate) fn frame_correlation_id(id: u32, data: &[u8]) -> ResponseData {
+    let mut framed: ResponseData = Vec::with_capacity(CORRELATION_ID_LEN + data.len());
+    framed.extend_from_slice(&id.to_be_bytes());
+    framed.extend_from_slice(data);
+    framed
+}
+
+/// Prefixes every `segment_size` chunk of a payload with the same correlation ID.
+///
+/// # Arguments
+///
+/// - `u32` - The correlation ID.
+/// - `&[u8]` - The payload.
+/// - `usize` - The size of each chunk before framing.
+///
+/// # Returns
+///
+/// - `ResponseData` - The framed segments, each `CORRELATION_ID_LEN` bytes longer.
+pub(crate) fn frame_correlation_id_segments(
+    id: u32,
+    data: &[u8],
+    segment_size: usize,
+) -> ResponseData {
+    let segments: usize = data.len().div_ceil(segment_size.max(1));
+    let mut framed: ResponseData = Vec::with_capacity(data.len() + segments * CORRELATION_ID_LEN);
+    for segment in data.chunks(segment_size.max(1)) {
+        framed.extend_from_slice(&id.to_be_bytes());
+        framed.extend_from_slice(segment);
+    }
+    framed
+}
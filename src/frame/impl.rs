@@ -0,0 +1,2 @@
+/// The number of bytes of the big-endian correlation ID that prefixes each framed datagram.
+pub const CORRELATION_ID_LEN: usize = 4;
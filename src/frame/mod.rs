@@ -0,0 +1,8 @@
+mod r#fn;
+mod r#impl;
+
+pub(crate) use r#fn::*;
+
+pub use r#impl::*;
+
+use super::*;
@@ -20,3 +20,56 @@ where
         })
     })
 }
+
+/// Creates a new `ServerHookHandler` from an async closure.
+///
+/// The closure is stored as a `HookHandler` and receives its own clone of the
+/// `Context` for every invocation.
+///
+/// # Arguments
+///
+/// - `F` - The closure taking a `Context` and returning a future.
+///
+/// # Returns
+///
+/// - `ServerHookHandler` - A new `ServerHookHandler` instance.
+#[inline(always)]
+pub fn server_hook_fn_factory<F, Fut>(hook: F) -> ServerHookHandler
+where
+    F: FnContextSendSyncStatic<Fut, ()>,
+    Fut: FutureSendStatic<()>,
+{
+    let handler: HookHandler<()> =
+        Arc::new(move |ctx: Context| -> SendableAsyncTask<()> { Box::pin(hook(ctx)) });
+    Arc::new(move |ctx: &Context| -> SendableAsyncTask<()> { handler(ctx.clone()) })
+}
+
+/// Wraps a `ServerHookHandler` so that it is cancelled after a deadline.
+///
+/// When the deadline passes, the hook is dropped, the context is aborted so the
+/// rest of the chain is skipped, and `RequestError::Timeout` is stored on it; the
+/// server then runs the request timeout hooks as for a chain-wide timeout.
+///
+/// # Arguments
+///
+/// - `ServerHookHandler` - The hook to guard.
+/// - `Duration` - The deadline of a single invocation.
+///
+/// # Returns
+///
+/// - `ServerHookHandler` - The guarded hook.
+pub(crate) fn server_hook_timeout_factory(
+    hook: ServerHookHandler,
+    duration: Duration,
+) -> ServerHookHandler {
+    Arc::new(move |ctx: &Context| -> SendableAsyncTask<()> {
+        let hook: ServerHookHandler = hook.clone();
+        let ctx: Context = ctx.clone();
+        Box::pin(async move {
+            if timeout(duration, hook(&ctx)).await.is_err() {
+                ctx.set_aborted(true).await;
+                ctx.set_request_error(RequestError::Timeout(duration)).await;
+            }
+        })
+    })
+}
@@ -1,5 +1,28 @@
 use super::*;
 
+/// Blanket implementation of `FnContextSendSync` for any matching closure.
+impl<F, R> FnContextSendSync<R> for F where F: Fn(Context) -> R + Send + Sync {}
+
+/// Blanket implementation of `FnContextPinBoxSendSync` for closures returning a boxed task.
+impl<F, T> FnContextPinBoxSendSync<T> for F where F: FnContextSendSync<SendableAsyncTask<T>> {}
+
+/// Blanket implementation of `FnContextSendSyncStatic` for static closures returning a future.
+impl<F, Fut, T> FnContextSendSyncStatic<Fut, T> for F
+where
+    F: FnContextSendSync<Fut> + 'static,
+    Fut: Future<Output = T> + Send,
+{
+}
+
+/// Blanket implementation of `FutureSendStatic` for static, sendable futures.
+impl<F, T> FutureSendStatic<T> for F where F: Future<Output = T> + Send + 'static {}
+
+/// Blanket implementation of `FutureSend` for sendable futures.
+impl<F, T> FutureSend<T> for F where F: Future<Output = T> + Send {}
+
+/// Blanket implementation of `FnPinBoxFutureSend` for task factories.
+impl<F, T> FnPinBoxFutureSend<T> for F where F: Fn() -> SendableAsyncTask<T> + Send + Sync + 'static {}
+
 /// Provides a default implementation for `ServerControlHook`.
 impl Default for ServerControlHook {
     /// Creates a default `ServerControlHook` with no-op hooks.
@@ -12,6 +35,14 @@ impl Default for ServerControlHook {
         Self {
             wait_hook: Arc::new(|| Box::pin(async {})),
             shutdown_hook: Arc::new(|| Box::pin(async {})),
+            graceful_shutdown_hook: Arc::new(|| Box::pin(async {})),
+            in_flight_hook: Arc::new(|| 0),
+            dropped_hook: Arc::new(|| 0),
+            read_errors_dropped_hook: Arc::new(|| 0),
+            rate_limited_hook: Arc::new(|| 0),
+            rate_limited_hooks_dropped_hook: Arc::new(|| 0),
+            sessions_hook: Arc::new(|| 0),
+            local_addrs: Vec::new(),
         }
     }
 }
@@ -42,6 +73,98 @@ impl ServerControlHook {
         self.shutdown_hook = hook;
     }
 
+    /// Sets the graceful shutdown hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `hook` - The graceful shutdown hook to set.
+    pub(crate) fn set_graceful_shutdown_hook(
+        &mut self,
+        hook: Arc<dyn Fn() -> SendableAsyncTask<()> + Send + Sync>,
+    ) {
+        self.graceful_shutdown_hook = hook;
+    }
+
+    /// Sets the in-flight counter hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `hook` - The hook reporting the number of running handlers.
+    pub(crate) fn set_in_flight_hook(&mut self, hook: Arc<dyn Fn() -> usize + Send + Sync>) {
+        self.in_flight_hook = hook;
+    }
+
+    /// Sets the dropped datagram counter hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `hook` - The hook reporting dropped datagrams.
+    pub(crate) fn set_dropped_hook(&mut self, hook: Arc<dyn Fn() -> u64 + Send + Sync>) {
+        self.dropped_hook = hook;
+    }
+
+    /// Sets the dropped read error counter hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `hook` - The hook reporting receive errors that skipped the read error hooks.
+    pub(crate) fn set_read_errors_dropped_hook(
+        &mut self,
+        hook: Arc<dyn Fn() -> u64 + Send + Sync>,
+    ) {
+        self.read_errors_dropped_hook = hook;
+    }
+
+    /// Sets the session counter hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `hook` - The hook reporting tracked sessions.
+    pub(crate) fn set_sessions_hook(&mut self, hook: Arc<dyn Fn() -> usize + Send + Sync>) {
+        self.sessions_hook = hook;
+    }
+
+    /// Sets the rate limited hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `hook` - The hook reporting rejected datagrams.
+    pub(crate) fn set_rate_limited_hook(&mut self, hook: Arc<dyn Fn() -> u64 + Send + Sync>) {
+        self.rate_limited_hook = hook;
+    }
+
+    /// Sets the dropped rate limited hook counter hook.
+    ///
+    /// # Arguments
+    ///
+    /// - `hook` - The hook reporting rejected datagrams that skipped the rate limited hooks.
+    pub(crate) fn set_rate_limited_hooks_dropped_hook(
+        &mut self,
+        hook: Arc<dyn Fn() -> u64 + Send + Sync>,
+    ) {
+        self.rate_limited_hooks_dropped_hook = hook;
+    }
+
+    /// Sets the local addresses the server is bound to.
+    ///
+    /// # Arguments
+    ///
+    /// - `local_addrs` - The bound local addresses.
+    pub(crate) fn set_local_addrs(&mut self, local_addrs: Vec<SocketAddr>) {
+        self.local_addrs = local_addrs;
+    }
+
+    /// Gets the local addresses the server is bound to.
+    ///
+    /// Useful when binding port `0` to discover the ports chosen by the OS.
+    ///
+    /// # Returns
+    ///
+    /// - `&Vec<SocketAddr>` - The bound local addresses.
+    pub fn get_local_addrs(&self) -> &Vec<SocketAddr> {
+        &self.local_addrs
+    }
+
     /// Gets the wait hook.
     ///
     /// # Returns
@@ -60,6 +183,144 @@ impl ServerControlHook {
         self.shutdown_hook.clone()
     }
 
+    /// Gets the graceful shutdown hook.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<dyn Fn() -> SendableAsyncTask<()> + Send + Sync>` - The graceful shutdown hook.
+    pub fn get_graceful_shutdown_hook(
+        &self,
+    ) -> Arc<dyn Fn() -> SendableAsyncTask<()> + Send + Sync> {
+        self.graceful_shutdown_hook.clone()
+    }
+
+    /// Gets the in-flight counter hook.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<dyn Fn() -> usize + Send + Sync>` - The hook reporting running handlers.
+    pub fn get_in_flight_hook(&self) -> Arc<dyn Fn() -> usize + Send + Sync> {
+        self.in_flight_hook.clone()
+    }
+
+    /// Gets the number of request handlers currently running.
+    ///
+    /// Read error and rate limited hook chains run apart from the handlers and
+    /// are not counted.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of in-flight handlers.
+    pub fn in_flight(&self) -> usize {
+        (self.get_in_flight_hook())()
+    }
+
+    /// Gets the dropped datagram counter hook.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<dyn Fn() -> u64 + Send + Sync>` - The hook reporting dropped datagrams.
+    pub fn get_dropped_hook(&self) -> Arc<dyn Fn() -> u64 + Send + Sync> {
+        self.dropped_hook.clone()
+    }
+
+    /// Gets the number of datagrams dropped because the handler limit was reached.
+    ///
+    /// With `OverloadPolicy::DropOldest` this counts the cancelled handlers.
+    /// Receive errors are counted by `read_errors_dropped` instead.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The dropped datagram count.
+    pub fn dropped(&self) -> u64 {
+        (self.get_dropped_hook())()
+    }
+
+    /// Gets the dropped read error counter hook.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<dyn Fn() -> u64 + Send + Sync>` - The hook reporting receive errors that skipped the read error hooks.
+    pub fn get_read_errors_dropped_hook(&self) -> Arc<dyn Fn() -> u64 + Send + Sync> {
+        self.read_errors_dropped_hook.clone()
+    }
+
+    /// Gets the number of receive errors whose read error hooks were skipped.
+    ///
+    /// Read error hooks run on their own tracker, bounded by
+    /// `ServerConfig::max_read_error_in_flight`; errors arriving while that
+    /// budget is exhausted, or while no read error hooks are registered, are
+    /// only counted here.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The skipped receive error count.
+    pub fn read_errors_dropped(&self) -> u64 {
+        (self.get_read_errors_dropped_hook())()
+    }
+
+    /// Gets the session counter hook.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<dyn Fn() -> usize + Send + Sync>` - The hook reporting tracked sessions.
+    pub fn get_sessions_hook(&self) -> Arc<dyn Fn() -> usize + Send + Sync> {
+        self.sessions_hook.clone()
+    }
+
+    /// Gets the number of sessions currently tracked.
+    ///
+    /// Idle sessions count until the next sweep removes them.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The session count.
+    pub fn sessions(&self) -> usize {
+        (self.get_sessions_hook())()
+    }
+
+    /// Gets the rate limited hook.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<dyn Fn() -> u64 + Send + Sync>` - The hook reporting rejected datagrams.
+    pub fn get_rate_limited_hook(&self) -> Arc<dyn Fn() -> u64 + Send + Sync> {
+        self.rate_limited_hook.clone()
+    }
+
+    /// Gets the number of datagrams the rate limiter rejected.
+    ///
+    /// Every rejected datagram is counted, including those for which the rate
+    /// limited hooks were skipped because their budget was exhausted.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The rejected datagram count.
+    pub fn rate_limited(&self) -> u64 {
+        (self.get_rate_limited_hook())()
+    }
+
+    /// Gets the dropped rate limited hook counter hook.
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<dyn Fn() -> u64 + Send + Sync>` - The hook reporting rejected datagrams that skipped the rate limited hooks.
+    pub fn get_rate_limited_hooks_dropped_hook(&self) -> Arc<dyn Fn() -> u64 + Send + Sync> {
+        self.rate_limited_hooks_dropped_hook.clone()
+    }
+
+    /// Gets the number of rejected datagrams whose rate limited hooks were skipped.
+    ///
+    /// The hooks are skipped while `RateLimit::max_hooks_in_flight` chains are
+    /// already running.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The skipped rate limited hook count.
+    pub fn rate_limited_hooks_dropped(&self) -> u64 {
+        (self.get_rate_limited_hooks_dropped_hook())()
+    }
+
     /// Waits for the server to finish.
     pub async fn wait(&self) {
         (self.get_wait_hook())().await;
@@ -69,6 +330,14 @@ impl ServerControlHook {
     pub async fn shutdown(&self) {
         (self.get_shutdown_hook())().await;
     }
+
+    /// Shuts down the server gracefully.
+    ///
+    /// Stops receiving, waits for in-flight handlers up to the configured
+    /// shutdown timeout, then cancels whatever is still running.
+    pub async fn graceful_shutdown(&self) {
+        (self.get_graceful_shutdown_hook())().await;
+    }
 }
 
 /// Implementation of methods for `HandlerState`.
@@ -78,23 +347,127 @@ impl HandlerState {
     /// # Arguments
     ///
     /// - `socket` - The network socket.
+    /// - `local_addr` - The local address the datagram arrived on.
+    /// - `tracker` - The tracker owning the request handler tasks.
     ///
     /// # Returns
     ///
     /// - `Self` - The newly created handler state.
     #[inline(always)]
-    pub(crate) fn new(socket: ArcRwLockUdpSocket) -> Self {
-        Self { socket }
+    pub(crate) fn new(
+        socket: ArcUdpSocket,
+        local_addr: Option<SocketAddr>,
+        tracker: TaskTracker,
+    ) -> Self {
+        Self {
+            socket,
+            local_addr,
+            truncated: false,
+            tracker,
+            reply_batcher: None,
+            correlation_id: None,
+            sessions: None,
+        }
     }
 
     /// Gets the socket.
     ///
     /// # Returns
     ///
-    /// - `ArcRwLockUdpSocket` - The network socket.
-    pub(crate) fn get_socket(&self) -> ArcRwLockUdpSocket {
+    /// - `ArcUdpSocket` - The network socket.
+    pub(crate) fn get_socket(&self) -> ArcUdpSocket {
         self.socket.clone()
     }
+
+    /// Gets the local address.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SocketAddr>` - The local address the datagram arrived on.
+    pub(crate) fn get_local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+
+    /// Gets the task tracker.
+    ///
+    /// # Returns
+    ///
+    /// - `&TaskTracker` - The tracker owning the request handler tasks.
+    pub(crate) fn get_tracker(&self) -> &TaskTracker {
+        &self.tracker
+    }
+
+    /// Gets the reply batcher.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ReplyBatcher>` - The batcher for replies, if batching is enabled.
+    pub(crate) fn get_reply_batcher(&self) -> Option<ReplyBatcher> {
+        self.reply_batcher.clone()
+    }
+
+    /// Sets the reply batcher.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<ReplyBatcher>` - The batcher for replies.
+    pub(crate) fn set_reply_batcher(&mut self, reply_batcher: Option<ReplyBatcher>) {
+        self.reply_batcher = reply_batcher;
+    }
+
+    /// Gets whether the datagram was truncated.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - The truncated flag.
+    pub(crate) fn get_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Sets whether the datagram was truncated.
+    ///
+    /// # Arguments
+    ///
+    /// - `bool` - The truncated flag.
+    pub(crate) fn set_truncated(&mut self, truncated: bool) {
+        self.truncated = truncated;
+    }
+
+    /// Gets the correlation ID.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u32>` - The correlation ID of the datagram, if framing is enabled.
+    pub(crate) fn get_correlation_id(&self) -> Option<u32> {
+        self.correlation_id
+    }
+
+    /// Sets the correlation ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<u32>` - The correlation ID of the datagram.
+    pub(crate) fn set_correlation_id(&mut self, correlation_id: Option<u32>) {
+        self.correlation_id = correlation_id;
+    }
+
+    /// Gets the session store.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SessionStore>` - The sessions of the server run.
+    pub(crate) fn get_sessions(&self) -> Option<SessionStore> {
+        self.sessions.clone()
+    }
+
+    /// Sets the session store.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<SessionStore>` - The sessions of the server run.
+    pub(crate) fn set_sessions(&mut self, sessions: Option<SessionStore>) {
+        self.sessions = sessions;
+    }
 }
 
 /// Implementation of `ServerHook` for `DefaultServerHook`.
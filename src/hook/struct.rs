@@ -11,10 +11,28 @@ pub struct ServerControlHook {
     /// This is typically used to wait for the server to stop accepting connections before
     /// the application exits.
     pub(crate) wait_hook: Arc<dyn Fn() -> SendableAsyncTask<()> + Send + Sync>,
-    /// A hook that, when called, initiates a graceful shutdown of the server.
-    /// This will stop the server from accepting new connections and allow existing ones
-    /// to complete.
+    /// A hook that, when called, initiates a shutdown of the server.
+    /// This will stop the server from receiving new datagrams; handlers that are
+    /// already running are left to complete on their own.
     pub(crate) shutdown_hook: Arc<dyn Fn() -> SendableAsyncTask<()> + Send + Sync>,
+    /// A hook that, when called, stops receiving and drains in-flight handlers.
+    /// Handlers still running after the configured shutdown timeout are cancelled,
+    /// and the returned future completes once draining is finished.
+    pub(crate) graceful_shutdown_hook: Arc<dyn Fn() -> SendableAsyncTask<()> + Send + Sync>,
+    /// A hook that reports the number of request handlers currently running.
+    pub(crate) in_flight_hook: Arc<dyn Fn() -> usize + Send + Sync>,
+    /// A hook that reports how many datagrams were dropped because the handler limit was reached.
+    pub(crate) dropped_hook: Arc<dyn Fn() -> u64 + Send + Sync>,
+    /// A hook that reports how many receive errors skipped the read error hooks because their limit was reached.
+    pub(crate) read_errors_dropped_hook: Arc<dyn Fn() -> u64 + Send + Sync>,
+    /// A hook that reports how many datagrams the rate limiter rejected.
+    pub(crate) rate_limited_hook: Arc<dyn Fn() -> u64 + Send + Sync>,
+    /// A hook that reports how many rejected datagrams skipped the rate limited hooks because their limit was reached.
+    pub(crate) rate_limited_hooks_dropped_hook: Arc<dyn Fn() -> u64 + Send + Sync>,
+    /// A hook that reports the number of sessions currently tracked.
+    pub(crate) sessions_hook: Arc<dyn Fn() -> usize + Send + Sync>,
+    /// The local addresses the server's sockets are bound to.
+    pub(crate) local_addrs: Vec<SocketAddr>,
 }
 
 /// Represents the state associated with a single connection handler.
@@ -25,5 +43,17 @@ pub struct ServerControlHook {
 #[derive(Clone)]
 pub(crate) struct HandlerState {
     /// A reference to the underlying network socket for the connection.
-    pub(super) socket: ArcRwLockUdpSocket,
+    pub(super) socket: ArcUdpSocket,
+    /// The local address the datagram arrived on.
+    pub(super) local_addr: Option<SocketAddr>,
+    /// Whether the datagram being handled was truncated.
+    pub(super) truncated: bool,
+    /// The tracker that owns the request handler tasks of this server run.
+    pub(super) tracker: TaskTracker,
+    /// The batcher coalescing replies sent from the socket, if batching is enabled.
+    pub(super) reply_batcher: Option<ReplyBatcher>,
+    /// The correlation ID of the datagram being handled, if framing is enabled.
+    pub(super) correlation_id: Option<u32>,
+    /// The sessions of the server run.
+    pub(super) sessions: Option<SessionStore>,
 }
@@ -4,40 +4,85 @@
 //! building UDP servers with request-response handling.
 
 mod attribute;
+mod client;
+mod codec;
 mod common;
 mod config;
 mod context;
 mod error;
+mod frame;
 mod hook;
+mod limit;
+mod middleware;
 mod panic;
 mod request;
 mod response;
+mod router;
 mod server;
+mod session;
 mod socket;
+mod task;
 mod utils;
 
 pub use {
-    attribute::*, common::*, config::*, context::*, error::*, hook::*, panic::*, request::*,
-    response::*, server::*, socket::*, utils::*,
+    attribute::*, client::*, codec::*, common::*, config::*, context::*, error::*, frame::*,
+    hook::*, middleware::*, panic::*, request::*, response::*, router::*, server::*, session::*,
+    socket::*, utils::*,
 };
 
-pub use tokio;
+use {limit::*, task::*};
+
+pub use {bytes, tokio};
 
 use std::{
-    any::Any,
-    collections::HashMap,
+    any::{Any, TypeId, type_name},
+    collections::{BTreeMap, HashMap},
     fmt::{self, Display},
     future::Future,
+    io::ErrorKind,
+    mem::MaybeUninit,
     net::{IpAddr, SocketAddr},
+    num::NonZeroUsize,
+    panic::{AssertUnwindSafe, PanicHookInfo, catch_unwind, set_hook, take_hook},
     pin::Pin,
-    sync::Arc,
+    sync::{
+        Arc, Mutex, MutexGuard, Once, PoisonError,
+        atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering},
+    },
+    task::{Context as TaskContext, Poll},
+    time::{Duration, Instant},
+};
+
+use bytes::{Bytes, BytesMut};
+#[cfg(any(feature = "json", feature = "cbor", feature = "msgpack"))]
+use serde::{Serialize, de::DeserializeOwned};
+use socket2::{Domain, Protocol, SockRef, Socket, Type};
+#[cfg(any(feature = "json", feature = "cbor", feature = "msgpack"))]
+use std::marker::PhantomData;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use std::{
+    io::IoSlice,
+    mem::zeroed,
+    net::{Ipv4Addr, Ipv6Addr},
+    os::fd::AsRawFd,
+    ptr::{read_unaligned, write_unaligned},
 };
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use socket2::MsgHdr;
+#[cfg(unix)]
+use socket2::{MaybeUninitSlice, MsgHdrMut, SockAddr, SockAddrStorage};
 use tokio::{
-    net::UdpSocket,
+    io::Interest,
+    net::{UdpSocket, lookup_host},
+    runtime::{Builder, EnterGuard, Handle, Runtime},
     sync::{
-        RwLock, RwLockReadGuard, RwLockWriteGuard,
+        Mutex as AsyncMutex, MutexGuard as AsyncMutexGuard, Notify, OnceCell, RwLock,
+        RwLockReadGuard, RwLockWriteGuard,
+        futures::Notified,
+        mpsc, oneshot,
         watch::{Receiver, Sender, channel},
     },
-    task::JoinHandle,
+    task::{AbortHandle, JoinError, JoinHandle, futures::TaskLocalFuture},
+    time::{Interval, MissedTickBehavior, error::Elapsed, interval, sleep, timeout},
 };
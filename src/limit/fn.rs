@@ -0,0 +1,31 @@
+use super::*;
+
+/// Masks an address to its subnet so that every source in the subnet shares a bucket.
+///
+/// IPv4-mapped IPv6 addresses are treated as IPv4.
+///
+/// # Arguments
+///
+/// - `IpAddr` - The source address.
+/// - `u8` - The IPv4 prefix length.
+/// - `u8` - The IPv6 prefix length.
+///
+/// # Returns
+///
+/// - `IpAddr` - The masked address.
+pub(crate) fn mask_ip(ip: IpAddr, ipv4_prefix: u8, ipv6_prefix: u8) -> IpAddr {
+    match ip.to_canonical() {
+        IpAddr::V4(ip) => {
+            let mask: u32 = u32::MAX
+                .checked_shl(32 - u32::from(ipv4_prefix.min(32)))
+                .unwrap_or(0);
+            IpAddr::from((u32::from(ip) & mask).to_be_bytes())
+        }
+        IpAddr::V6(ip) => {
+            let mask: u128 = u128::MAX
+                .checked_shl(128 - u32::from(ipv6_prefix.min(128)))
+                .unwrap_or(0);
+            IpAddr::from((u128::from(ip) & mask).to_be_bytes())
+        }
+    }
+}
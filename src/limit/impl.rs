@@ -0,0 +1,165 @@
+use super::*;
+
+/// The bucket count below which idle buckets are never pruned.
+const MIN_PRUNE_AT: usize = 1024;
+
+/// The minimum time between prunes while the source limit is reached.
+///
+/// Pruning walks every bucket, so a flood of new sources at the limit must not
+/// trigger it for every datagram.
+const FULL_PRUNE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Implementation of methods for `TokenBucket`.
+impl TokenBucket {
+    /// Creates a full bucket.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RateLimit` - The rate limit.
+    /// - `Instant` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The bucket.
+    fn full(rate_limit: &RateLimit, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(rate_limit.get_burst()),
+            updated: now,
+        }
+    }
+
+    /// Adds the tokens accumulated since the last refill, up to the burst size.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RateLimit` - The rate limit.
+    /// - `Instant` - The current time.
+    fn refill(&mut self, rate_limit: &RateLimit, now: Instant) {
+        let elapsed: f64 = now.saturating_duration_since(self.updated).as_secs_f64();
+        let refilled: f64 = self.tokens + elapsed * f64::from(rate_limit.get_requests_per_second());
+        self.tokens = refilled.min(f64::from(rate_limit.get_burst()));
+        self.updated = now;
+    }
+
+    /// Checks whether the bucket is full again and therefore safe to forget.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RateLimit` - The rate limit.
+    /// - `Instant` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the bucket has refilled completely.
+    fn is_idle(&self, rate_limit: &RateLimit, now: Instant) -> bool {
+        let mut bucket: TokenBucket = *self;
+        bucket.refill(rate_limit, now);
+        bucket.tokens >= f64::from(rate_limit.get_burst())
+    }
+}
+
+/// Implementation of methods for `RateLimiter`.
+impl RateLimiter {
+    /// Creates a rate limiter with no buckets.
+    ///
+    /// # Arguments
+    ///
+    /// - `RateLimit` - The rate limit.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The rate limiter.
+    pub(crate) fn new(rate_limit: RateLimit) -> Self {
+        Self(Arc::new(RateLimiterData {
+            rate_limit,
+            state: Mutex::new(RateLimiterState {
+                buckets: HashMap::new(),
+                prune_at: MIN_PRUNE_AT.min(rate_limit.get_max_sources()),
+                pruned: Instant::now(),
+            }),
+            rejected: AtomicU64::new(0),
+            hooks: TaskTracker::new(
+                Some(rate_limit.get_max_hooks_in_flight()),
+                OverloadPolicy::DropNewest,
+            ),
+        }))
+    }
+
+    /// Gets the number of datagrams rejected so far.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The rejected datagram count.
+    pub(crate) fn rejected(&self) -> u64 {
+        self.0.rejected.load(Ordering::Relaxed)
+    }
+
+    /// Gets the tracker running the rate limited hooks.
+    ///
+    /// # Returns
+    ///
+    /// - `&TaskTracker` - The bounded hook tracker.
+    pub(crate) fn get_hooks(&self) -> &TaskTracker {
+        &self.0.hooks
+    }
+
+    /// Takes a token for the given source.
+    ///
+    /// Idle buckets are pruned when the bucket count reaches the next threshold,
+    /// which doubles with the live bucket count but never exceeds `max_sources`.
+    /// A source that is not tracked yet is rejected while `max_sources` buckets
+    /// are live.
+    ///
+    /// # Arguments
+    ///
+    /// - `IpAddr` - The source address.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the datagram is allowed, `false` if it exceeds the limit.
+    pub(crate) fn check(&self, ip: IpAddr) -> bool {
+        let rate_limit: &RateLimit = &self.0.rate_limit;
+        let key: IpAddr = mask_ip(
+            ip,
+            rate_limit.get_ipv4_prefix(),
+            rate_limit.get_ipv6_prefix(),
+        );
+        let now: Instant = Instant::now();
+        let mut state: MutexGuard<'_, RateLimiterState> = self.0.state.lock().unwrap_or_else(
+            |error: PoisonError<MutexGuard<'_, RateLimiterState>>| error.into_inner(),
+        );
+        let max_sources: usize = rate_limit.get_max_sources();
+        let known: bool = state.buckets.contains_key(&key);
+        if !known
+            && state.buckets.len() >= state.prune_at
+            && (state.buckets.len() < max_sources
+                || now.saturating_duration_since(state.pruned) >= FULL_PRUNE_INTERVAL)
+        {
+            state
+                .buckets
+                .retain(|_, bucket: &mut TokenBucket| !bucket.is_idle(rate_limit, now));
+            state.prune_at = state
+                .buckets
+                .len()
+                .saturating_mul(2)
+                .max(MIN_PRUNE_AT)
+                .min(max_sources);
+            state.pruned = now;
+        }
+        if !known && state.buckets.len() >= max_sources {
+            self.0.rejected.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+        let bucket: &mut TokenBucket = state
+            .buckets
+            .entry(key)
+            .or_insert_with(|| TokenBucket::full(rate_limit, now));
+        bucket.refill(rate_limit, now);
+        if bucket.tokens < 1.0 {
+            self.0.rejected.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+        bucket.tokens -= 1.0;
+        true
+    }
+}
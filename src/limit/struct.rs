@@ -0,0 +1,37 @@
+use super::*;
+
+/// The token bucket of a single source.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TokenBucket {
+    /// The tokens currently available.
+    pub(super) tokens: f64,
+    /// When the bucket was last refilled.
+    pub(super) updated: Instant,
+}
+
+/// The buckets of all sources seen recently.
+#[derive(Debug)]
+pub(crate) struct RateLimiterState {
+    /// The buckets keyed by masked source address.
+    pub(super) buckets: HashMap<IpAddr, TokenBucket>,
+    /// The bucket count at which idle buckets are pruned next.
+    pub(super) prune_at: usize,
+    /// When idle buckets were last pruned.
+    pub(super) pruned: Instant,
+}
+
+/// Shared state behind a `RateLimiter`.
+pub(crate) struct RateLimiterData {
+    /// The configured rate limit.
+    pub(super) rate_limit: RateLimit,
+    /// The buckets of all sources seen recently.
+    pub(super) state: Mutex<RateLimiterState>,
+    /// The number of datagrams rejected so far.
+    pub(super) rejected: AtomicU64,
+    /// The tracker running the rate limited hooks, bounded to `max_hooks_in_flight`.
+    pub(super) hooks: TaskTracker,
+}
+
+/// Applies a per-source token bucket rate limit to incoming datagrams.
+#[derive(Clone)]
+pub(crate) struct RateLimiter(pub(super) Arc<RateLimiterData>);
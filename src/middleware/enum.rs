@@ -0,0 +1,14 @@
+use super::*;
+
+/// The result of running the rest of the chain through `Next::run`.
+#[derive(Clone, Debug)]
+pub enum NextOutcome {
+    /// Every remaining middleware and hook ran to the end.
+    Completed,
+    /// A hook aborted the context, skipping the hooks after it.
+    Aborted,
+    /// A middleware or hook panicked; the rest of that run was skipped.
+    Panicked(PanicData),
+    /// The run stopped with a request error, such as a per-hook `RequestError::Timeout`.
+    Error(RequestError),
+}
@@ -0,0 +1,43 @@
+use super::*;
+
+/// Creates a new `ServerMiddlewareHandler` from a type implementing `ServerMiddleware`.
+///
+/// # Arguments
+///
+/// - `ServerMiddleware` - The middleware type.
+///
+/// # Returns
+///
+/// - `ServerMiddlewareHandler` - A new `ServerMiddlewareHandler` instance.
+#[inline(always)]
+pub fn server_middleware_factory<M>() -> ServerMiddlewareHandler
+where
+    M: ServerMiddleware,
+{
+    Arc::new(move |ctx: &Context, next: Next| -> SendableAsyncTask<()> {
+        let ctx: Context = ctx.clone();
+        Box::pin(async move {
+            M::new(&ctx).await.handle(&ctx, next).await;
+        })
+    })
+}
+
+/// Creates a new `ServerMiddlewareHandler` from an async closure.
+///
+/// # Arguments
+///
+/// - `F` - The closure taking a `Context` and the `Next` continuation.
+///
+/// # Returns
+///
+/// - `ServerMiddlewareHandler` - A new `ServerMiddlewareHandler` instance.
+#[inline(always)]
+pub fn server_middleware_fn_factory<F, Fut>(middleware: F) -> ServerMiddlewareHandler
+where
+    F: FnContextNextSendSync<Fut>,
+    Fut: FutureSendStatic<()>,
+{
+    Arc::new(move |ctx: &Context, next: Next| -> SendableAsyncTask<()> {
+        Box::pin(middleware(ctx.clone(), next))
+    })
+}
@@ -0,0 +1,94 @@
+use super::*;
+
+/// Blanket implementation of `FnContextNextSendSync` for any matching closure.
+impl<F, Fut> FnContextNextSendSync<Fut> for F
+where
+    F: Fn(Context, Next) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send,
+{
+}
+
+/// Implementation of methods for `Next`.
+impl Next {
+    /// Creates a continuation that starts at the first middleware.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerMiddlewareList` - The middleware to run, outermost first.
+    /// - `ServerHookList` - The hooks to run after the last middleware.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new `Next` instance.
+    pub(crate) fn new(middleware: ServerMiddlewareList, hooks: ServerHookList) -> Self {
+        Self {
+            middleware: Arc::new(middleware),
+            hooks: Arc::new(hooks),
+            index: 0,
+        }
+    }
+
+    /// Runs the rest of the chain.
+    ///
+    /// The remaining middleware run in order; once they have all called their
+    /// continuation, the hooks run sequentially until one aborts the context.
+    /// The aborted and replied flags and the request error are reset first, so
+    /// every run, including a retry, starts from a clean state. A panic in the
+    /// rest of the chain is caught and returned instead of unwinding through the
+    /// calling middleware; returning from the middleware without re-raising it
+    /// means the server does not run the task panic hooks for it.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Context` - The request context.
+    ///
+    /// # Returns
+    ///
+    /// - `NextOutcome` - How the run ended.
+    pub async fn run(&self, ctx: &Context) -> NextOutcome {
+        ctx.reset_run().await;
+        if let Err(mut panic_data) = CatchUnwind::new(self.dispatch(ctx)).await {
+            if let Ok(Some(location)) = PANIC_LOCATION.try_with(|slot: &PanicLocationSlot| {
+                slot.lock().ok().and_then(|mut guard| guard.take())
+            }) {
+                panic_data.set_location(location);
+            }
+            return NextOutcome::Panicked(panic_data);
+        }
+        match ctx.try_get_request_error().await {
+            Some(error) => NextOutcome::Error(error),
+            None if ctx.get_aborted().await => NextOutcome::Aborted,
+            None => NextOutcome::Completed,
+        }
+    }
+
+    /// Builds the future for the middleware at the current index, or for the hooks.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Context` - The request context.
+    ///
+    /// # Returns
+    ///
+    /// - `SendableAsyncTask<()>` - The future running the rest of the chain.
+    fn dispatch(&self, ctx: &Context) -> SendableAsyncTask<()> {
+        if let Some(middleware) = self.middleware.get(self.index) {
+            let next: Next = Self {
+                middleware: self.middleware.clone(),
+                hooks: self.hooks.clone(),
+                index: self.index + 1,
+            };
+            return middleware(ctx, next);
+        }
+        let hooks: Arc<ServerHookList> = self.hooks.clone();
+        let ctx: Context = ctx.clone();
+        Box::pin(async move {
+            for hook in hooks.iter() {
+                hook(&ctx).await;
+                if ctx.get_aborted().await {
+                    return;
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,10 @@
+mod r#enum;
+mod r#fn;
+mod r#impl;
+mod r#struct;
+mod r#trait;
+mod r#type;
+
+pub use {r#enum::*, r#fn::*, r#struct::*, r#trait::*, r#type::*};
+
+use super::*;
@@ -0,0 +1,16 @@
+use super::*;
+
+/// The continuation handed to a `ServerMiddleware`.
+///
+/// Running it executes the remaining middleware followed by the request hooks
+/// and the routed handler chain. It can be cloned and run more than once, for
+/// example to retry the rest of the chain.
+#[derive(Clone)]
+pub struct Next {
+    /// The full middleware list of the request.
+    pub(super) middleware: Arc<ServerMiddlewareList>,
+    /// The hooks run after the last middleware.
+    pub(super) hooks: Arc<ServerHookList>,
+    /// The index of the next middleware to run.
+    pub(super) index: usize,
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+/// Trait for around-style middleware wrapping the rest of the hook chain.
+///
+/// Unlike a `ServerHook`, which runs to completion before the next hook starts,
+/// a middleware receives a `Next` continuation and decides when, and how many
+/// times, the rest of the chain runs. Code after `next.run(ctx).await` observes
+/// the outcome of every later middleware and hook.
+pub trait ServerMiddleware: Send + Sync + 'static {
+    /// Creates a new instance of this middleware from the context.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Context` - The request context containing all request/response data.
+    ///
+    /// # Returns
+    ///
+    /// A future that resolves to a new instance of this middleware.
+    fn new(ctx: &Context) -> impl Future<Output = Self> + Send;
+
+    /// Executes the middleware around the rest of the chain.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Context` - The request context for accessing request/response data.
+    /// - `Next` - The continuation running the remaining middleware and hooks.
+    ///
+    /// # Returns
+    ///
+    /// A future that resolves when the middleware is complete.
+    fn handle(self, ctx: &Context, next: Next) -> impl Future<Output = ()> + Send;
+}
+
+/// A trait for closures usable as middleware.
+pub trait FnContextNextSendSync<Fut>: Fn(Context, Next) -> Fut + Send + Sync + 'static
+where
+    Fut: Future<Output = ()> + Send,
+{
+}
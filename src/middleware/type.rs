@@ -0,0 +1,11 @@
+use super::*;
+
+/// Type alias for a shared middleware handler.
+///
+/// The handler takes a `&Context` and the `Next` continuation and returns
+/// a pinned, boxed future that resolves to `()`.
+pub type ServerMiddlewareHandler =
+    Arc<dyn Fn(&Context, Next) -> SendableAsyncTask<()> + Send + Sync>;
+
+/// Type alias for a list of middleware handlers, outermost first.
+pub type ServerMiddlewareList = Vec<ServerMiddlewareHandler>;
@@ -0,0 +1,20 @@
+use super::*;
+
+/// Installs a process-wide panic hook that records panic locations.
+///
+/// The hook writes the `file:line` of a panic into the `PANIC_LOCATION`
+/// slot of the current request task, if any, and then delegates to the
+/// previously installed hook. Installation happens at most once.
+pub(crate) fn install_panic_location_hook() {
+    PANIC_HOOK_INSTALL.call_once(|| {
+        let previous: Box<dyn Fn(&PanicHookInfo<'_>) + Send + Sync> = take_hook();
+        set_hook(Box::new(move |info: &PanicHookInfo<'_>| {
+            if let (Some(location), Ok(slot)) = (info.location(), PANIC_LOCATION.try_get())
+                && let Ok(mut guard) = slot.lock()
+            {
+                *guard = Some(format!("{}:{}", location.file(), location.line()));
+            }
+            previous(info);
+        }));
+    });
+}
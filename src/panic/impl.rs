@@ -39,6 +39,9 @@ impl PanicData {
 
     /// Creates a new PanicData from a JoinError (for panics in spawned tasks).
     ///
+    /// The message is taken from the panic payload when it is a string,
+    /// otherwise from the error's display output.
+    ///
     /// # Arguments
     ///
     /// - `JoinError` - The JoinError from a panicked task.
@@ -46,9 +49,32 @@ impl PanicData {
     /// # Returns
     ///
     /// - `Self` - A new PanicData instance.
-    pub fn from_join_error(error: tokio::task::JoinError) -> Self {
+    pub fn from_join_error(error: JoinError) -> Self {
+        match error.try_into_panic() {
+            Ok(payload) => Self::from_payload(payload),
+            Err(error) => Self::from_message(error.to_string()),
+        }
+    }
+
+    /// Creates a new PanicData from a caught panic payload.
+    ///
+    /// The message is taken from the payload when it is a string.
+    ///
+    /// # Arguments
+    ///
+    /// - `Box<dyn Any + Send>` - The payload returned by `catch_unwind`.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new PanicData instance.
+    pub(crate) fn from_payload(payload: Box<dyn Any + Send>) -> Self {
+        let message: String = payload
+            .downcast_ref::<&str>()
+            .map(|message: &&str| (*message).to_owned())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Unknown panic payload".to_owned());
         Self {
-            message: error.to_string(),
+            message,
             location: None,
         }
     }
@@ -106,3 +132,47 @@ impl Display for PanicData {
 
 /// Implementation of StdError for PanicData.
 impl std::error::Error for PanicData {}
+
+/// Implementation of methods for CatchUnwind.
+impl<F> CatchUnwind<F>
+where
+    F: Future + Unpin,
+{
+    /// Wraps a future so that its panics are caught.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The pinned future to guard.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The guarded future.
+    pub(crate) fn new(future: F) -> Self {
+        Self { future }
+    }
+}
+
+/// Implementation of Future for CatchUnwind.
+impl<F> Future for CatchUnwind<F>
+where
+    F: Future + Unpin,
+{
+    type Output = Result<F::Output, PanicData>;
+
+    /// Polls the wrapped future, turning a panic into `Err(PanicData)`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut TaskContext<'_>` - The task context of the poll.
+    ///
+    /// # Returns
+    ///
+    /// - `Poll<Self::Output>` - The output of the wrapped future, or the caught panic.
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        let future: Pin<&mut F> = Pin::new(&mut self.future);
+        match catch_unwind(AssertUnwindSafe(|| future.poll(cx))) {
+            Ok(poll) => poll.map(Ok),
+            Err(payload) => Poll::Ready(Err(PanicData::from_payload(payload))),
+        }
+    }
+}
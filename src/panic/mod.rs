@@ -1,6 +1,13 @@
+mod r#fn;
 mod r#impl;
+mod r#static;
 mod r#struct;
+mod r#type;
 
-pub use r#struct::*;
+pub(crate) use {r#fn::*, r#static::*, r#type::*};
+
+pub use r#struct::PanicData;
+
+pub(crate) use r#struct::CatchUnwind;
 
 use super::*;
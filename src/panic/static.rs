@@ -0,0 +1,10 @@
+use super::*;
+
+tokio::task_local! {
+    /// Slot that receives the `file:line` location of a panic raised
+    /// inside the current request task.
+    pub(crate) static PANIC_LOCATION: PanicLocationSlot;
+}
+
+/// Guards the one-time installation of the panic location hook.
+pub(crate) static PANIC_HOOK_INSTALL: Once = Once::new();
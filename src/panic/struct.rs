@@ -1,3 +1,12 @@
+/// A future that catches panics raised while polling the wrapped future.
+///
+/// Resolves to `Err(PanicData)` instead of unwinding when the wrapped future
+/// panics, so a request chain can be guarded without spawning another task.
+pub(crate) struct CatchUnwind<F> {
+    /// The guarded future.
+    pub(super) future: F,
+}
+
 /// Represents data captured from a panic.
 ///
 /// This structure holds information about a panic that occurred during
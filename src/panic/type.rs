@@ -0,0 +1,4 @@
+use super::*;
+
+/// Shared slot used to carry a panic location out of a panicking task.
+pub(crate) type PanicLocationSlot = Arc<Mutex<Option<String>>>;
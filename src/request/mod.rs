@@ -1,3 +1,5 @@
 mod r#type;
 
 pub use r#type::*;
+
+use super::*;
@@ -1,2 +1,7 @@
-/// Type alias for request data (byte vector).
-pub type Request = Vec<u8>;
+use super::*;
+
+/// Type alias for request data.
+///
+/// A reference-counted byte buffer: cloning a request or slicing it does not copy
+/// the datagram.
+pub type Request = Bytes;
@@ -41,6 +41,30 @@ impl Response {
         &self.data
     }
 
+    /// Replaces the response data.
+    ///
+    /// # Arguments
+    ///
+    /// - `Into<ResponseData>` - The new response data.
+    pub fn set_data<T>(&mut self, data: T)
+    where
+        T: Into<ResponseData>,
+    {
+        self.data = data.into();
+    }
+
+    /// Appends bytes to the response data.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The bytes to append.
+    pub fn append<T>(&mut self, data: T)
+    where
+        T: AsRef<[u8]>,
+    {
+        self.data.extend_from_slice(data.as_ref());
+    }
+
     /// Sends the response through the specified socket.
     ///
     /// # Arguments
@@ -51,17 +75,61 @@ impl Response {
     /// # Returns
     ///
     /// - `ResponseResult` - Result of the send operation.
+    #[deprecated(note = "use `send_udp`, the socket needs no lock")]
     pub async fn send(
         &self,
         socket_opt: &Option<ArcRwLockUdpSocket>,
         addr_opt: &Option<SocketAddr>,
     ) -> ResponseResult {
-        if let Some(socket_lock) = socket_opt {
-            let socket: tokio::sync::RwLockReadGuard<'_, UdpSocket> =
-                socket_lock.get_read_lock().await;
+        let socket_opt: Option<ArcUdpSocket> =
+            socket_opt.as_ref().map(ArcRwLockUdpSocket::get_udp_socket);
+        self.send_udp(&socket_opt, addr_opt).await
+    }
+
+    /// Sends the response through the specified shared socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Option<ArcUdpSocket>` - Optional socket reference.
+    /// - `&Option<SocketAddr>` - Optional target address.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the send operation.
+    pub async fn send_udp(
+        &self,
+        socket_opt: &Option<ArcUdpSocket>,
+        addr_opt: &Option<SocketAddr>,
+    ) -> ResponseResult {
+        self.send_from(socket_opt, addr_opt, &None).await
+    }
+
+    /// Sends the response through the specified socket from a specific local address.
+    ///
+    /// On Linux the source IP is selected with `IP_PKTINFO`/`IPV6_PKTINFO`, so replies
+    /// leave from the address the request arrived on even when the socket is bound
+    /// to a wildcard address. Elsewhere the local address is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Option<ArcUdpSocket>` - Optional socket reference.
+    /// - `&Option<SocketAddr>` - Optional target address.
+    /// - `&Option<SocketAddr>` - Optional local address to send from.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseResult` - Result of the send operation.
+    pub async fn send_from(
+        &self,
+        socket_opt: &Option<ArcUdpSocket>,
+        addr_opt: &Option<SocketAddr>,
+        local_addr_opt: &Option<SocketAddr>,
+    ) -> ResponseResult {
+        if let Some(socket) = socket_opt {
             if let Some(addr) = addr_opt {
-                socket
-                    .send_to(self.get_data(), addr)
+                let source_ip: Option<IpAddr> =
+                    local_addr_opt.map(|local_addr: SocketAddr| local_addr.ip());
+                send_datagram(socket, self.get_data(), *addr, source_ip)
                     .await
                     .map_err(|error: std::io::Error| ResponseError::SendError(error.to_string()))?;
                 return Ok(());
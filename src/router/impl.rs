@@ -0,0 +1,222 @@
+use super::*;
+
+/// Implementation of methods for RouteChain.
+impl RouteChain {
+    /// Gets the handlers of the route.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerHookList` - The handlers run for matching datagrams.
+    pub(crate) fn get_hooks(&self) -> &ServerHookList {
+        &self.hooks
+    }
+
+    /// Gets the deadline of the route.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The deadline overriding the request timeout, if any.
+    pub(crate) fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Sets the deadline of the route.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<Duration>` - The deadline overriding the request timeout.
+    pub(crate) fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+}
+
+/// Implementation of methods for Router.
+impl Router {
+    /// Gets the chain of a leading opcode byte, creating it when missing.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The opcode.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RouteChain` - The chain of the opcode.
+    pub(crate) fn opcode_chain(&mut self, opcode: u8) -> &mut RouteChain {
+        self.opcodes.entry(opcode).or_default()
+    }
+
+    /// Gets the chain of a magic prefix, creating it when missing.
+    ///
+    /// New prefixes are inserted so that longer prefixes are matched first.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The prefix.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RouteChain` - The chain of the prefix.
+    pub(crate) fn prefix_chain(&mut self, prefix: Vec<u8>) -> &mut RouteChain {
+        let index: usize = match self
+            .prefixes
+            .iter()
+            .position(|(existing, _)| *existing == prefix)
+        {
+            Some(index) => index,
+            None => {
+                let index: usize = self
+                    .prefixes
+                    .partition_point(|(existing, _)| existing.len() >= prefix.len());
+                self.prefixes.insert(index, (prefix, RouteChain::default()));
+                index
+            }
+        };
+        &mut self.prefixes[index].1
+    }
+
+    /// Gets the chain of an extracted route key, creating it when missing.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The route key.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RouteChain` - The chain of the key.
+    pub(crate) fn key_chain(&mut self, key: Vec<u8>) -> &mut RouteChain {
+        self.keys.entry(key).or_default()
+    }
+
+    /// Gets the existing chain of a leading opcode byte.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The opcode.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&mut RouteChain>` - The chain, or `None` if no handler is registered for it.
+    pub(crate) fn try_get_mut_opcode_chain(&mut self, opcode: u8) -> Option<&mut RouteChain> {
+        self.opcodes.get_mut(&opcode)
+    }
+
+    /// Gets the existing chain of a magic prefix.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The prefix.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&mut RouteChain>` - The chain, or `None` if no handler is registered for it.
+    pub(crate) fn try_get_mut_prefix_chain(&mut self, prefix: &[u8]) -> Option<&mut RouteChain> {
+        self.prefixes
+            .iter_mut()
+            .find(|(existing, _)| existing.as_slice() == prefix)
+            .map(|(_, chain)| chain)
+    }
+
+    /// Gets the existing chain of an extracted route key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The route key.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&mut RouteChain>` - The chain, or `None` if no handler is registered for it.
+    pub(crate) fn try_get_mut_key_chain(&mut self, key: &[u8]) -> Option<&mut RouteChain> {
+        self.keys.get_mut(key)
+    }
+
+    /// Gets the fallback chain.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RouteChain` - The chain for datagrams that match no route.
+    pub(crate) fn fallback_chain(&mut self) -> &mut RouteChain {
+        &mut self.fallback
+    }
+
+    /// Appends a handler to the chain of a leading opcode byte.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The opcode.
+    /// - `ServerHookHandler` - The handler.
+    pub(crate) fn add_opcode(&mut self, opcode: u8, hook: ServerHookHandler) {
+        self.opcode_chain(opcode).hooks.push(hook);
+    }
+
+    /// Appends a handler to the chain of a magic prefix.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The prefix.
+    /// - `ServerHookHandler` - The handler.
+    pub(crate) fn add_prefix(&mut self, prefix: Vec<u8>, hook: ServerHookHandler) {
+        self.prefix_chain(prefix).hooks.push(hook);
+    }
+
+    /// Appends a handler to the chain of an extracted route key.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The route key.
+    /// - `ServerHookHandler` - The handler.
+    pub(crate) fn add_key(&mut self, key: Vec<u8>, hook: ServerHookHandler) {
+        self.key_chain(key).hooks.push(hook);
+    }
+
+    /// Sets the extractor that computes the route key of a datagram.
+    ///
+    /// # Arguments
+    ///
+    /// - `RouteKeyExtractor` - The key extractor.
+    pub(crate) fn set_key_extractor(&mut self, key_extractor: RouteKeyExtractor) {
+        self.key_extractor = Some(key_extractor);
+    }
+
+    /// Appends a handler to the fallback chain.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHookHandler` - The handler.
+    pub(crate) fn add_fallback(&mut self, hook: ServerHookHandler) {
+        self.fallback.hooks.push(hook);
+    }
+
+    /// Finds the handler chain for a datagram.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The request payload.
+    ///
+    /// # Returns
+    ///
+    /// - `&RouteChain` - The matching chain, or the fallback chain.
+    pub(crate) fn resolve(&self, data: &[u8]) -> &RouteChain {
+        if let Some(chain) = data
+            .first()
+            .and_then(|opcode: &u8| self.opcodes.get(opcode))
+        {
+            return chain;
+        }
+        if let Some((_, chain)) = self
+            .prefixes
+            .iter()
+            .find(|(prefix, _)| data.starts_with(prefix))
+        {
+            return chain;
+        }
+        if let Some(chain) = self
+            .key_extractor
+            .as_ref()
+            .and_then(|key_extractor: &RouteKeyExtractor| key_extractor(data))
+            .and_then(|key: Vec<u8>| self.keys.get(&key))
+        {
+            return chain;
+        }
+        &self.fallback
+    }
+}
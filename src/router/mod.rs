@@ -0,0 +1,9 @@
+mod r#impl;
+mod r#struct;
+mod r#type;
+
+pub(crate) use r#struct::*;
+
+pub use r#type::*;
+
+use super::*;
@@ -0,0 +1,29 @@
+use super::*;
+
+/// A routed handler chain and its optional deadline.
+#[derive(Clone, Default)]
+pub(crate) struct RouteChain {
+    /// The handlers run for datagrams matching the route.
+    pub(super) hooks: ServerHookList,
+    /// The deadline overriding the server's request timeout, if any.
+    pub(super) timeout: Option<Duration>,
+}
+
+/// Dispatches datagrams to handler chains registered against a discriminator.
+///
+/// A datagram is matched against its leading opcode byte first, then against the
+/// registered magic prefixes (longest first), then against the key returned by
+/// the key extractor. Datagrams matching none of them run the fallback chain.
+#[derive(Clone, Default)]
+pub(crate) struct Router {
+    /// The handler chains keyed by the leading opcode byte.
+    pub(super) opcodes: HashMap<u8, RouteChain>,
+    /// The handler chains keyed by magic prefix, longest prefix first.
+    pub(super) prefixes: Vec<(Vec<u8>, RouteChain)>,
+    /// The handler chains keyed by the extracted route key.
+    pub(super) keys: HashMap<Vec<u8>, RouteChain>,
+    /// Extracts the route key from a datagram.
+    pub(super) key_extractor: Option<RouteKeyExtractor>,
+    /// The handler chain for datagrams that match no route.
+    pub(super) fallback: RouteChain,
+}
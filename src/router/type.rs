@@ -0,0 +1,8 @@
+use super::*;
+
+/// A type alias for a user-supplied route key extractor.
+///
+/// The extractor receives the request payload and returns the key used to look
+/// up a handler chain registered with `Server::route_key`, or `None` if the
+/// datagram carries no key.
+pub type RouteKeyExtractor = Arc<dyn Fn(&[u8]) -> Option<Vec<u8>> + Send + Sync>;
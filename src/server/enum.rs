@@ -0,0 +1,8 @@
+/// How the server should stop once a shutdown is requested.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ShutdownMode {
+    /// Stop receiving and leave running handlers detached.
+    Immediate,
+    /// Stop receiving, drain running handlers up to the shutdown timeout, then cancel the rest.
+    Graceful,
+}
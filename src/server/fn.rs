@@ -0,0 +1,29 @@
+use super::*;
+
+/// The delay before receiving again after the second of consecutive receive errors.
+pub(crate) const MIN_RECEIVE_ERROR_BACKOFF: Duration = Duration::from_millis(10);
+
+/// The longest delay before receiving again after consecutive receive errors.
+pub(crate) const MAX_RECEIVE_ERROR_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Pins the calling thread to a single CPU core.
+///
+/// Pinning is best effort: failures are ignored and the thread keeps its
+/// previous affinity. On platforms other than Linux and Android this does nothing.
+///
+/// # Arguments
+///
+/// - `usize` - The index of the core to pin to.
+pub(crate) fn pin_current_thread(core: usize) {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    // SAFETY: the set is zero-initialized before use, `core` is bounded by the set
+    // size, and `sched_setaffinity` only reads the set.
+    unsafe {
+        let mut set: libc::cpu_set_t = zeroed();
+        libc::CPU_ZERO(&mut set);
+        libc::CPU_SET(core % libc::CPU_SETSIZE as usize, &mut set);
+        let _: libc::c_int = libc::sched_setaffinity(0, size_of::<libc::cpu_set_t>(), &set);
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    let _: usize = core;
+}
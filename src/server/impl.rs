@@ -12,8 +12,17 @@ impl Default for ServerData {
         Self {
             server_config: ServerConfigData::default(),
             hook: vec![],
+            middleware: vec![],
             task_panic: vec![],
             read_error: vec![],
+            rate_limited: vec![],
+            router: Router::default(),
+            codec: None,
+            decode_error: vec![],
+            request_timeout: vec![],
+            state: Arc::default(),
+            session_created: vec![],
+            session_expired: vec![],
         }
     }
 }
@@ -47,8 +56,14 @@ impl PartialEq for ServerData {
     fn eq(&self, other: &Self) -> bool {
         self.server_config == other.server_config
             && self.hook.len() == other.hook.len()
+            && self.middleware.len() == other.middleware.len()
             && self.task_panic.len() == other.task_panic.len()
             && self.read_error.len() == other.read_error.len()
+            && self.rate_limited.len() == other.rate_limited.len()
+            && self.decode_error.len() == other.decode_error.len()
+            && self.request_timeout.len() == other.request_timeout.len()
+            && self.session_created.len() == other.session_created.len()
+            && self.session_expired.len() == other.session_expired.len()
     }
 }
 
@@ -95,6 +110,33 @@ impl ServerData {
         &mut self.hook
     }
 
+    /// Gets the middleware list.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerMiddlewareList` - Reference to the middleware list.
+    pub(crate) fn get_middleware(&self) -> &ServerMiddlewareList {
+        &self.middleware
+    }
+
+    /// Gets a mutable reference to the middleware list.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut ServerMiddlewareList` - Mutable reference to the middleware list.
+    pub(crate) fn get_mut_middleware(&mut self) -> &mut ServerMiddlewareList {
+        &mut self.middleware
+    }
+
+    /// Gets the task panic list.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerHookList` - Reference to the task panic list.
+    pub(crate) fn get_task_panic(&self) -> &ServerHookList {
+        &self.task_panic
+    }
+
     /// Gets a mutable reference to the task panic list.
     ///
     /// # Returns
@@ -103,6 +145,170 @@ impl ServerData {
     pub(crate) fn get_mut_task_panic(&mut self) -> &mut ServerHookList {
         &mut self.task_panic
     }
+
+    /// Gets the read error list.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerHookList` - Reference to the read error list.
+    pub(crate) fn get_read_error(&self) -> &ServerHookList {
+        &self.read_error
+    }
+
+    /// Gets a mutable reference to the read error list.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut ServerHookList` - Mutable reference to the read error list.
+    pub(crate) fn get_mut_read_error(&mut self) -> &mut ServerHookList {
+        &mut self.read_error
+    }
+
+    /// Gets the rate limited list.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerHookList` - Reference to the rate limited list.
+    pub(crate) fn get_rate_limited(&self) -> &ServerHookList {
+        &self.rate_limited
+    }
+
+    /// Gets a mutable reference to the rate limited list.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut ServerHookList` - Mutable reference to the rate limited list.
+    pub(crate) fn get_mut_rate_limited(&mut self) -> &mut ServerHookList {
+        &mut self.rate_limited
+    }
+
+    /// Gets the router.
+    ///
+    /// # Returns
+    ///
+    /// - `&Router` - Reference to the routed handler chains.
+    pub(crate) fn get_router(&self) -> &Router {
+        &self.router
+    }
+
+    /// Gets a mutable reference to the router.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Router` - Mutable reference to the routed handler chains.
+    pub(crate) fn get_mut_router(&mut self) -> &mut Router {
+        &mut self.router
+    }
+
+    /// Gets the codec.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&SharedCodec>` - The codec attached to the server, if any.
+    pub(crate) fn get_codec(&self) -> Option<&SharedCodec> {
+        self.codec.as_ref()
+    }
+
+    /// Sets the codec.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<SharedCodec>` - The codec to attach.
+    pub(crate) fn set_codec(&mut self, codec: Option<SharedCodec>) {
+        self.codec = codec;
+    }
+
+    /// Gets the decode error list.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerHookList` - Reference to the decode error list.
+    pub(crate) fn get_decode_error(&self) -> &ServerHookList {
+        &self.decode_error
+    }
+
+    /// Gets a mutable reference to the decode error list.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut ServerHookList` - Mutable reference to the decode error list.
+    pub(crate) fn get_mut_decode_error(&mut self) -> &mut ServerHookList {
+        &mut self.decode_error
+    }
+
+    /// Gets the request timeout list.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerHookList` - Reference to the request timeout list.
+    pub(crate) fn get_request_timeout(&self) -> &ServerHookList {
+        &self.request_timeout
+    }
+
+    /// Gets a mutable reference to the request timeout list.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut ServerHookList` - Mutable reference to the request timeout list.
+    pub(crate) fn get_mut_request_timeout(&mut self) -> &mut ServerHookList {
+        &mut self.request_timeout
+    }
+
+    /// Gets the session created list.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerHookList` - Reference to the session created list.
+    pub(crate) fn get_session_created(&self) -> &ServerHookList {
+        &self.session_created
+    }
+
+    /// Gets a mutable reference to the session created list.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut ServerHookList` - Mutable reference to the session created list.
+    pub(crate) fn get_mut_session_created(&mut self) -> &mut ServerHookList {
+        &mut self.session_created
+    }
+
+    /// Gets the session expired list.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerHookList` - Reference to the session expired list.
+    pub(crate) fn get_session_expired(&self) -> &ServerHookList {
+        &self.session_expired
+    }
+
+    /// Gets a mutable reference to the session expired list.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut ServerHookList` - Mutable reference to the session expired list.
+    pub(crate) fn get_mut_session_expired(&mut self) -> &mut ServerHookList {
+        &mut self.session_expired
+    }
+
+    /// Gets the shared state store.
+    ///
+    /// # Returns
+    ///
+    /// - `&SharedStateStore` - Reference to the typed application state.
+    pub(crate) fn get_state(&self) -> &SharedStateStore {
+        &self.state
+    }
+
+    /// Gets a mutable reference to the state store.
+    ///
+    /// Contexts created earlier keep the store they were given.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut ThreadSafeStateStore` - Mutable reference to the typed application state.
+    pub(crate) fn get_mut_state(&mut self) -> &mut ThreadSafeStateStore {
+        Arc::make_mut(&mut self.state)
+    }
 }
 
 /// Implements the `PartialEq` trait for `Server`.
@@ -202,6 +408,124 @@ impl Server {
         self
     }
 
+    /// Registers a hook from an async closure.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The closure taking a `Context` and returning a future.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn hook_fn<F, Fut>(&self, hook: F) -> &Self
+    where
+        F: FnContextSendSyncStatic<Fut, ()>,
+        Fut: FutureSendStatic<()>,
+    {
+        self.write()
+            .await
+            .get_mut_hook()
+            .push(server_hook_fn_factory(hook));
+        self
+    }
+
+    /// Registers a hook using the `ServerHook` trait with its own deadline.
+    ///
+    /// When a single invocation outlives `timeout`, it is cancelled, the rest of
+    /// the chain is skipped and the request timeout hooks run, regardless of the
+    /// route's or the server's request timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHook` - The hook type that implements `ServerHook`.
+    /// - `Duration` - The deadline of the hook.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn hook_with_timeout<H>(&self, timeout: Duration) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_hook()
+            .push(server_hook_timeout_factory(
+                server_hook_factory::<H>(),
+                timeout,
+            ));
+        self
+    }
+
+    /// Registers a hook from an async closure with its own deadline.
+    ///
+    /// # Arguments
+    ///
+    /// - `Duration` - The deadline of the hook.
+    /// - `F` - The closure taking a `Context` and returning a future.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn hook_fn_with_timeout<F, Fut>(&self, timeout: Duration, hook: F) -> &Self
+    where
+        F: FnContextSendSyncStatic<Fut, ()>,
+        Fut: FutureSendStatic<()>,
+    {
+        self.write()
+            .await
+            .get_mut_hook()
+            .push(server_hook_timeout_factory(
+                server_hook_fn_factory(hook),
+                timeout,
+            ));
+        self
+    }
+
+    /// Registers a middleware using the `ServerMiddleware` trait.
+    ///
+    /// Middleware wrap the request hooks and the routed handler chain, in
+    /// registration order: the first registered middleware is the outermost.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerMiddleware` - The middleware type that implements `ServerMiddleware`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn middleware<M>(&self) -> &Self
+    where
+        M: ServerMiddleware,
+    {
+        self.write()
+            .await
+            .get_mut_middleware()
+            .push(server_middleware_factory::<M>());
+        self
+    }
+
+    /// Registers a middleware from an async closure.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The closure taking a `Context` and the `Next` continuation.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn middleware_fn<F, Fut>(&self, middleware: F) -> &Self
+    where
+        F: FnContextNextSendSync<Fut>,
+        Fut: FutureSendStatic<()>,
+    {
+        self.write()
+            .await
+            .get_mut_middleware()
+            .push(server_middleware_fn_factory(middleware));
+        self
+    }
+
     /// Registers a task panic handler using the `ServerHook` trait.
     ///
     /// # Arguments
@@ -222,116 +546,1461 @@ impl Server {
         self
     }
 
-    /// Creates and binds a UDP socket based on the server's configuration.
+    /// Registers a task panic handler from an async closure.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The closure taking a `Context` and returning a future.
     ///
     /// # Returns
     ///
-    /// - `Result<UdpSocket, ServerError>` - A `Result` containing the bound `UdpSocket` on success,
-    ///   or a `ServerError` on failure.
-    async fn create_udp_socket(&self) -> Result<UdpSocket, ServerError> {
-        let config: ServerConfigData = self.read().await.get_server_config().clone();
-        let host: &String = config.get_host();
-        let port: u16 = config.get_port();
-        let addr: String = format!("{host}:{port}");
-        UdpSocket::bind(&addr)
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn task_panic_fn<F, Fut>(&self, hook: F) -> &Self
+    where
+        F: FnContextSendSyncStatic<Fut, ()>,
+        Fut: FutureSendStatic<()>,
+    {
+        self.write()
             .await
-            .map_err(|error: std::io::Error| ServerError::UdpBind(error.to_string()))
+            .get_mut_task_panic()
+            .push(server_hook_fn_factory(hook));
+        self
     }
 
-    /// Spawns a new asynchronous task to handle a single client request.
+    /// Registers a read error handler using the `ServerHook` trait.
+    ///
+    /// The handler is invoked with a context carrying the `RequestError`
+    /// whenever receiving a datagram fails.
     ///
     /// # Arguments
     ///
-    /// - `HandlerState` - The handler state containing the socket and config.
-    /// - `Request` - The received request data.
-    /// - `SocketAddr` - The client's socket address.
-    async fn spawn_request_handler(
-        &self,
-        state: HandlerState,
-        data: Request,
-        client_addr: SocketAddr,
-    ) {
-        let server: Server = self.clone();
-        tokio::spawn(async move {
-            server.handle_request(state, data, client_addr).await;
-        });
+    /// - `ServerHook` - The handler type that implements `ServerHook`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn read_error<H>(&self) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_read_error()
+            .push(server_hook_factory::<H>());
+        self
     }
 
-    /// The core request handling pipeline.
-    ///
-    /// This function orchestrates the execution of handlers.
+    /// Registers a read error handler from an async closure.
     ///
     /// # Arguments
     ///
-    /// - `HandlerState` - The handler state.
-    /// - `Request` - The request data.
-    /// - `SocketAddr` - The client's socket address.
-    async fn handle_request(&self, state: HandlerState, data: Request, client_addr: SocketAddr) {
-        let ctx: Context = Context::new(&state.get_socket(), &data, client_addr);
-        for hook in self.read().await.get_hook().iter() {
-            hook(&ctx).await;
-            if ctx.get_aborted().await {
-                return;
-            }
-        }
+    /// - `F` - The closure taking a `Context` and returning a future.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn read_error_fn<F, Fut>(&self, hook: F) -> &Self
+    where
+        F: FnContextSendSyncStatic<Fut, ()>,
+        Fut: FutureSendStatic<()>,
+    {
+        self.write()
+            .await
+            .get_mut_read_error()
+            .push(server_hook_fn_factory(hook));
+        self
     }
 
-    /// Starts the server, binds to the configured address, and begins listening for requests.
+    /// Registers a handler for datagrams rejected by the rate limiter.
     ///
-    /// This is the main entry point to launch the server. It will create a UDP socket,
-    /// bind to the configured address, and then enter the request acceptance loop.
+    /// The handler is invoked with a context carrying the rejected request and
+    /// the client address. Without any such handler, rejected datagrams are
+    /// discarded without spawning a task.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHook` - The handler type that implements `ServerHook`.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing a `ServerControlHook` on success.
-    /// Returns an error if the server fails to start.
-    pub async fn run(&self) -> Result<ServerControlHook, ServerError> {
-        let socket: UdpSocket = self.create_udp_socket().await?;
-        let socket: ArcRwLockUdpSocket = ArcRwLockUdpSocket::from_socket(socket);
-        let server: Server = self.clone();
-        let (wait_sender, wait_receiver) = channel(());
-        let (shutdown_sender, mut shutdown_receiver) = channel(());
-        let server_config: ServerConfigData = self.read().await.get_server_config().clone();
-        let buffer_size: usize = server_config.get_buffer_size();
-        let accept_requests: JoinHandle<()> = tokio::spawn(async move {
-            let mut buf: Vec<u8> = vec![0u8; buffer_size];
-            loop {
-                match socket.get_read_lock().await.recv_from(&mut buf).await {
-                    Ok((data_len, client_addr)) => {
-                        let data: Request = buf[..data_len].to_vec();
-                        let state: HandlerState = HandlerState::new(socket.clone());
-                        server.spawn_request_handler(state, data, client_addr).await;
-                    }
-                    Err(e) => {
-                        eprintln!("UDP receive error: {e}");
-                    }
-                }
-            }
-        });
-        let wait_hook: SharedAsyncTaskFactory<()> = Arc::new(move || {
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn rate_limited<H>(&self) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_rate_limited()
+            .push(server_hook_factory::<H>());
+        self
+    }
+
+    /// Registers a rate limited handler from an async closure.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The closure taking a `Context` and returning a future.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn rate_limited_fn<F, Fut>(&self, hook: F) -> &Self
+    where
+        F: FnContextSendSyncStatic<Fut, ()>,
+        Fut: FutureSendStatic<()>,
+    {
+        self.write()
+            .await
+            .get_mut_rate_limited()
+            .push(server_hook_fn_factory(hook));
+        self
+    }
+
+    /// Routes datagrams whose first byte is `opcode` to a handler.
+    ///
+    /// Routed handlers run after the hooks registered with `hook`, and only for
+    /// the datagrams they match. Registering several handlers for the same opcode
+    /// builds a chain that runs in registration order.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The leading opcode byte.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn route_opcode<H>(&self, opcode: u8) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_router()
+            .add_opcode(opcode, server_hook_factory::<H>());
+        self
+    }
+
+    /// Routes datagrams starting with a magic prefix to a handler.
+    ///
+    /// Prefixes are checked after opcodes, longest prefix first.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The prefix.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn route_prefix<H>(&self, prefix: impl AsRef<[u8]>) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_router()
+            .add_prefix(prefix.as_ref().to_vec(), server_hook_factory::<H>());
+        self
+    }
+
+    /// Routes datagrams whose extracted key equals `key` to a handler.
+    ///
+    /// Keys are computed by the extractor set with `route_key_extractor` and are
+    /// checked after opcodes and prefixes.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The route key.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn route_key<H>(&self, key: impl AsRef<[u8]>) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_router()
+            .add_key(key.as_ref().to_vec(), server_hook_factory::<H>());
+        self
+    }
+
+    /// Sets the function that extracts the route key from a datagram.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - Returns the route key of a payload, or `None` if it has none.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn route_key_extractor<F>(&self, key_extractor: F) -> &Self
+    where
+        F: Fn(&[u8]) -> Option<Vec<u8>> + Send + Sync + 'static,
+    {
+        self.write()
+            .await
+            .get_mut_router()
+            .set_key_extractor(Arc::new(key_extractor));
+        self
+    }
+
+    /// Registers a handler for datagrams that match no route.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHook` - The handler type that implements `ServerHook`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn fallback<H>(&self) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_router()
+            .add_fallback(server_hook_factory::<H>());
+        self
+    }
+
+    /// Overrides the request timeout for datagrams whose first byte is `opcode`.
+    ///
+    /// The route must already have a handler registered with `route_opcode`;
+    /// otherwise an empty route would shadow the routes matched after it.
+    ///
+    /// # Arguments
+    ///
+    /// - `u8` - The leading opcode byte.
+    /// - `Duration` - The deadline for the routed request.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&Self, ServerError>` - Reference to self for method chaining, or
+    ///   `ServerError::UnknownRoute` if no handler is registered for the opcode.
+    pub async fn route_opcode_timeout(
+        &self,
+        opcode: u8,
+        timeout: Duration,
+    ) -> Result<&Self, ServerError> {
+        self.write()
+            .await
+            .get_mut_router()
+            .try_get_mut_opcode_chain(opcode)
+            .ok_or_else(|| ServerError::UnknownRoute(format!("opcode {opcode:#04x}")))?
+            .set_timeout(Some(timeout));
+        Ok(self)
+    }
+
+    /// Overrides the request timeout for datagrams starting with a magic prefix.
+    ///
+    /// The route must already have a handler registered with `route_prefix`;
+    /// otherwise an empty route would shadow the routes matched after it.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The prefix.
+    /// - `Duration` - The deadline for the routed request.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&Self, ServerError>` - Reference to self for method chaining, or
+    ///   `ServerError::UnknownRoute` if no handler is registered for the prefix.
+    pub async fn route_prefix_timeout(
+        &self,
+        prefix: impl AsRef<[u8]>,
+        timeout: Duration,
+    ) -> Result<&Self, ServerError> {
+        let prefix: &[u8] = prefix.as_ref();
+        self.write()
+            .await
+            .get_mut_router()
+            .try_get_mut_prefix_chain(prefix)
+            .ok_or_else(|| ServerError::UnknownRoute(format!("prefix {prefix:?}")))?
+            .set_timeout(Some(timeout));
+        Ok(self)
+    }
+
+    /// Overrides the request timeout for datagrams whose extracted key equals `key`.
+    ///
+    /// The route must already have a handler registered with `route_key`;
+    /// otherwise an empty route would shadow the fallback.
+    ///
+    /// # Arguments
+    ///
+    /// - `AsRef<[u8]>` - The route key.
+    /// - `Duration` - The deadline for the routed request.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&Self, ServerError>` - Reference to self for method chaining, or
+    ///   `ServerError::UnknownRoute` if no handler is registered for the key.
+    pub async fn route_key_timeout(
+        &self,
+        key: impl AsRef<[u8]>,
+        timeout: Duration,
+    ) -> Result<&Self, ServerError> {
+        let key: &[u8] = key.as_ref();
+        self.write()
+            .await
+            .get_mut_router()
+            .try_get_mut_key_chain(key)
+            .ok_or_else(|| ServerError::UnknownRoute(format!("key {key:?}")))?
+            .set_timeout(Some(timeout));
+        Ok(self)
+    }
+
+    /// Overrides the request timeout for datagrams that match no route.
+    ///
+    /// # Arguments
+    ///
+    /// - `Duration` - The deadline for unrouted requests.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn fallback_timeout(&self, timeout: Duration) -> &Self {
+        self.write()
+            .await
+            .get_mut_router()
+            .fallback_chain()
+            .set_timeout(Some(timeout));
+        self
+    }
+
+    /// Registers a request timeout handler using the `ServerHook` trait.
+    ///
+    /// The handler is invoked with an aborted context carrying
+    /// `RequestError::Timeout` after a hook chain is cancelled for exceeding
+    /// its deadline, and may send a fallback reply.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHook` - The handler type that implements `ServerHook`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn request_timeout<H>(&self) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_request_timeout()
+            .push(server_hook_factory::<H>());
+        self
+    }
+
+    /// Registers a request timeout handler from an async closure.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The closure taking a `Context` and returning a future.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn request_timeout_fn<F, Fut>(&self, hook: F) -> &Self
+    where
+        F: FnContextSendSyncStatic<Fut, ()>,
+        Fut: FutureSendStatic<()>,
+    {
+        self.write()
+            .await
+            .get_mut_request_timeout()
+            .push(server_hook_fn_factory(hook));
+        self
+    }
+
+    /// Registers a session created handler using the `ServerHook` trait.
+    ///
+    /// The handler is invoked with the context of the request that created the
+    /// session, before `Context::try_get_session` returns.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHook` - The handler type that implements `ServerHook`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn session_created<H>(&self) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_session_created()
+            .push(server_hook_factory::<H>());
+        self
+    }
+
+    /// Registers a session created handler from an async closure.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The closure taking a `Context` and returning a future.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn session_created_fn<F, Fut>(&self, hook: F) -> &Self
+    where
+        F: FnContextSendSyncStatic<Fut, ()>,
+        Fut: FutureSendStatic<()>,
+    {
+        self.write()
+            .await
+            .get_mut_session_created()
+            .push(server_hook_fn_factory(hook));
+        self
+    }
+
+    /// Registers a session expired handler using the `ServerHook` trait.
+    ///
+    /// The handler is invoked with a context holding the expired session and an
+    /// empty request, addressed to the session's client.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHook` - The handler type that implements `ServerHook`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn session_expired<H>(&self) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_session_expired()
+            .push(server_hook_factory::<H>());
+        self
+    }
+
+    /// Registers a session expired handler from an async closure.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The closure taking a `Context` and returning a future.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn session_expired_fn<F, Fut>(&self, hook: F) -> &Self
+    where
+        F: FnContextSendSyncStatic<Fut, ()>,
+        Fut: FutureSendStatic<()>,
+    {
+        self.write()
+            .await
+            .get_mut_session_expired()
+            .push(server_hook_fn_factory(hook));
+        self
+    }
+
+    /// Registers a value of shared application state.
+    ///
+    /// The value is available to every hook through `Context::get_state`,
+    /// including from `ServerHook::new`. Registering another value of the same
+    /// type replaces the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// - `T` - The state value, typically an `Arc` around a pool or cache.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn state<T>(&self, value: T) -> &Self
+    where
+        T: AnySendSyncClone,
+    {
+        self.write()
+            .await
+            .get_mut_state()
+            .insert(TypeId::of::<T>(), Arc::new(value));
+        self
+    }
+
+    /// Attaches a codec that decodes every request before the hooks run.
+    ///
+    /// Handlers read the decoded request with `Context::get_decoded` and reply
+    /// with `Context::send_encoded`. Requests that fail to decode are passed to
+    /// the decode error hooks instead of the request hooks.
+    ///
+    /// # Arguments
+    ///
+    /// - `Codec` - The codec to attach.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn codec<C>(&self, codec: C) -> &Self
+    where
+        C: Codec,
+    {
+        self.write().await.set_codec(Some(Arc::new(codec)));
+        self
+    }
+
+    /// Registers a decode error handler using the `ServerHook` trait.
+    ///
+    /// The handler is invoked with a context carrying `RequestError::DecodeError`
+    /// whenever the attached codec rejects a request.
+    ///
+    /// # Arguments
+    ///
+    /// - `ServerHook` - The handler type that implements `ServerHook`.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn decode_error<H>(&self) -> &Self
+    where
+        H: ServerHook,
+    {
+        self.write()
+            .await
+            .get_mut_decode_error()
+            .push(server_hook_factory::<H>());
+        self
+    }
+
+    /// Registers a decode error handler from an async closure.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The closure taking a `Context` and returning a future.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - Reference to self for method chaining.
+    pub async fn decode_error_fn<F, Fut>(&self, hook: F) -> &Self
+    where
+        F: FnContextSendSyncStatic<Fut, ()>,
+        Fut: FutureSendStatic<()>,
+    {
+        self.write()
+            .await
+            .get_mut_decode_error()
+            .push(server_hook_fn_factory(hook));
+        self
+    }
+
+    /// Creates and binds a UDP socket for a single `host:port` address.
+    ///
+    /// The address is resolved and each resulting address is tried in turn,
+    /// applying the configured socket options before binding.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The `host:port` address to bind.
+    /// - `&ServerConfigData` - The server configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<UdpSocket, ServerError>` - A `Result` containing the bound `UdpSocket` on success,
+    ///   or a `ServerError` on failure.
+    async fn create_udp_socket(
+        &self,
+        addr: &str,
+        config: &ServerConfigData,
+    ) -> Result<UdpSocket, ServerError> {
+        let addrs: Vec<SocketAddr> = lookup_host(addr)
+            .await
+            .map_err(|error: std::io::Error| ServerError::UdpBind(format!("{addr}: {error}")))?
+            .collect();
+        let mut last_error: ServerError =
+            ServerError::UdpBind(format!("{addr}: no addresses resolved"));
+        for socket_addr in addrs {
+            match bind_udp_socket(socket_addr, config) {
+                Ok(socket) => return Ok(socket),
+                Err(error) => last_error = error,
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Creates and binds the UDP sockets for every configured bind address.
+    ///
+    /// With more than one worker, `SO_REUSEPORT` is enabled and that many sockets
+    /// are bound to each address; the extra sockets reuse the port the first one
+    /// was given, so port `0` works as well.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<UdpSocket>, ServerError>` - The bound sockets, or the first bind error.
+    async fn create_udp_sockets(&self) -> Result<Vec<UdpSocket>, ServerError> {
+        let mut config: ServerConfigData = self.read().await.get_server_config().clone();
+        let workers: usize = config.get_workers();
+        if workers > 1 {
+            config.set_reuse_port(Some(true));
+        }
+        let mut sockets: Vec<UdpSocket> = Vec::new();
+        for addr in config.get_bind_addrs() {
+            let socket: UdpSocket = self.create_udp_socket(&addr, &config).await?;
+            let local_addr: SocketAddr = socket.local_addr().map_err(|error: std::io::Error| {
+                ServerError::UdpBind(format!("{addr}: {error}"))
+            })?;
+            sockets.push(socket);
+            for _ in 1..workers {
+                sockets.push(bind_udp_socket(local_addr, &config)?);
+            }
+        }
+        Ok(sockets)
+    }
+
+    /// Spawns a new asynchronous task to handle a single client request.
+    ///
+    /// The task is registered with the handler state's tracker so that it can be
+    /// drained or cancelled during a graceful shutdown. When the configured handler
+    /// limit is reached, the overload policy may drop the datagram, cancel the oldest
+    /// handler, or wait here until a handler finishes.
+    ///
+    /// # Arguments
+    ///
+    /// - `HandlerState` - The handler state containing the socket and config.
+    /// - `Request` - The received request data.
+    /// - `SocketAddr` - The client's socket address.
+    async fn spawn_request_handler(
+        &self,
+        state: HandlerState,
+        data: Request,
+        client_addr: SocketAddr,
+    ) {
+        let server: Server = self.clone();
+        let tracker: TaskTracker = state.get_tracker().clone();
+        tracker
+            .spawn(async move {
+                server.handle_request(state, data, client_addr).await;
+            })
+            .await;
+    }
+
+    /// Spawns a new asynchronous task to run the read error hooks.
+    ///
+    /// The hooks run on the read error tracker, apart from the request handlers, so
+    /// a burst of receive errors never takes a handler slot. The tracker allows at
+    /// most `max_read_error_in_flight` chains at once; while that budget is exhausted
+    /// the error is only counted. The tracker is drained on graceful shutdown. Without
+    /// registered read error hooks the error is counted the same way.
+    ///
+    /// # Arguments
+    ///
+    /// - `&TaskTracker` - The tracker running the read error hooks.
+    /// - `HandlerState` - The handler state containing the socket.
+    /// - `RequestError` - The receive error.
+    /// - `Option<SocketAddr>` - The sender's address, if known.
+    async fn spawn_read_error_handler(
+        &self,
+        read_error_hooks: &TaskTracker,
+        state: HandlerState,
+        error: RequestError,
+        client_addr: Option<SocketAddr>,
+    ) {
+        let (read_error, shared_state): (ServerHookList, SharedStateStore) = {
+            let server: RwLockReadGuard<'_, ServerData> = self.read().await;
+            (server.get_read_error().clone(), server.get_state().clone())
+        };
+        if read_error.is_empty() {
+            read_error_hooks.count_dropped();
+            return;
+        }
+        read_error_hooks.try_spawn(async move {
+            let ctx: Context = Context::from_read_error(state.get_socket(), error, client_addr);
+            ctx.set_state(shared_state).await;
+            ctx.set_local_addr(state.get_local_addr()).await;
+            ctx.set_reply_batcher(state.get_reply_batcher()).await;
+            for hook in read_error.iter() {
+                hook(&ctx).await;
+                if ctx.get_aborted().await {
+                    return;
+                }
+            }
+        });
+    }
+
+    /// Spawns a new asynchronous task to run the rate limited hooks.
+    ///
+    /// The hooks run on the rate limiter's own tracker, which allows at most
+    /// `RateLimit::max_hooks_in_flight` chains at once; while that budget is exhausted
+    /// the skipped chain is only counted and nothing is allocated for it. Does
+    /// nothing when no rate limited hooks are registered.
+    ///
+    /// # Arguments
+    ///
+    /// - `&RateLimiter` - The rate limiter that rejected the datagram.
+    /// - `HandlerState` - The handler state containing the socket.
+    /// - `Request` - The rejected request data.
+    /// - `SocketAddr` - The client's socket address.
+    async fn spawn_rate_limited_handler(
+        &self,
+        rate_limiter: &RateLimiter,
+        state: HandlerState,
+        data: Request,
+        client_addr: SocketAddr,
+    ) {
+        let hooks: &TaskTracker = rate_limiter.get_hooks();
+        if hooks.is_full() {
+            hooks.count_dropped();
+            return;
+        }
+        let (rate_limited, shared_state): (ServerHookList, SharedStateStore) = {
+            let server: RwLockReadGuard<'_, ServerData> = self.read().await;
+            (
+                server.get_rate_limited().clone(),
+                server.get_state().clone(),
+            )
+        };
+        if rate_limited.is_empty() {
+            return;
+        }
+        hooks.try_spawn(async move {
+            let ctx: Context = Context::new(state.get_socket(), &data, client_addr);
+            ctx.set_state(shared_state).await;
+            ctx.set_local_addr(state.get_local_addr()).await;
+            ctx.set_reply_batcher(state.get_reply_batcher()).await;
+            ctx.set_correlation_id(state.get_correlation_id()).await;
+            for hook in rate_limited.iter() {
+                hook(&ctx).await;
+                if ctx.get_aborted().await {
+                    return;
+                }
+            }
+        });
+    }
+
+    /// The core request handling pipeline.
+    ///
+    /// Decodes the request with the server's codec, if any, then runs the middleware
+    /// around the request hooks, followed by the routed handler chain matching the
+    /// datagram. Decoding, the decode error hooks and the chain all run inside a
+    /// `CatchUnwind` guard on the handler's own task, so that a panic anywhere in
+    /// them is observed without a second spawn.
+    /// When a handler panics, the captured `PanicData` is stored on the context
+    /// and the task panic hooks are executed. When the chain outlives the route's
+    /// or the server's request timeout, it is cancelled and the request timeout
+    /// hooks are executed.
+    ///
+    /// # Arguments
+    ///
+    /// - `HandlerState` - The handler state.
+    /// - `Request` - The request data.
+    /// - `SocketAddr` - The client's socket address.
+    async fn handle_request(&self, state: HandlerState, data: Request, client_addr: SocketAddr) {
+        let ctx: Context = Context::new(state.get_socket(), &data, client_addr);
+        ctx.set_local_addr(state.get_local_addr()).await;
+        ctx.set_reply_batcher(state.get_reply_batcher()).await;
+        ctx.set_correlation_id(state.get_correlation_id()).await;
+        if state.get_truncated() {
+            ctx.set_truncated(true).await;
+        }
+        let sessions: Option<SessionStore> = state.get_sessions();
+        if let Some(sessions) = &sessions {
+            sessions.touch(client_addr);
+        }
+        ctx.set_sessions(sessions).await;
+        let (next, request_timeout, auto_flush, codec, shared_state): (
+            Next,
+            Option<Duration>,
+            bool,
+            Option<SharedCodec>,
+            SharedStateStore,
+        ) = {
+            let server: RwLockReadGuard<'_, ServerData> = self.read().await;
+            let route: &RouteChain = server.get_router().resolve(&data);
+            let mut hooks: ServerHookList = server.get_hook().clone();
+            hooks.extend(route.get_hooks().iter().cloned());
+            (
+                Next::new(server.get_middleware().clone(), hooks),
+                route
+                    .get_timeout()
+                    .or(server.get_server_config().get_request_timeout()),
+                server.get_server_config().get_auto_flush(),
+                server.get_codec().cloned(),
+                server.get_state().clone(),
+            )
+        };
+        ctx.set_state(shared_state).await;
+        let server: Server = self.clone();
+        let hook_ctx: Context = ctx.clone();
+        let location: PanicLocationSlot = Arc::new(Mutex::new(None));
+        let run: SendableAsyncTask<Result<bool, PanicData>> = Box::pin(async move {
+            if let Some(codec) = codec {
+                hook_ctx.set_codec(Some(codec.clone())).await;
+                match codec.decode_erased(&data) {
+                    Ok(decoded) => hook_ctx.set_decoded(decoded).await,
+                    Err(error) => {
+                        hook_ctx.set_request_error(error).await;
+                        server.handle_decode_error(&hook_ctx).await;
+                        return Ok(false);
+                    }
+                }
+            }
+            match next.run(&hook_ctx).await {
+                NextOutcome::Panicked(panic_data) => Err(panic_data),
+                _ => Ok(true),
+            }
+        });
+        let chain: TaskLocalFuture<
+            PanicLocationSlot,
+            CatchUnwind<SendableAsyncTask<Result<bool, PanicData>>>,
+        > = PANIC_LOCATION.scope(location.clone(), CatchUnwind::new(run));
+        let result: Result<bool, PanicData> = match request_timeout {
+            Some(duration) => match timeout(duration, chain).await {
+                Ok(result) => result.and_then(|result: Result<bool, PanicData>| result),
+                Err(_) => {
+                    ctx.set_aborted(true).await;
+                    ctx.set_request_error(RequestError::Timeout(duration)).await;
+                    self.handle_request_timeout(&ctx, duration).await;
+                    return;
+                }
+            },
+            None => chain
+                .await
+                .and_then(|result: Result<bool, PanicData>| result),
+        };
+        if let Some(RequestError::Timeout(duration)) = ctx.try_get_request_error().await {
+            self.handle_request_timeout(&ctx, duration).await;
+            return;
+        }
+        match result {
+            Ok(true) if auto_flush => self.flush_response(&ctx).await,
+            Ok(_) => {}
+            Err(mut panic_data) => {
+                if let Some(location) = location.lock().ok().and_then(|mut guard| guard.take())
+                    && panic_data.get_location().is_none()
+                {
+                    panic_data.set_location(location);
+                }
+                ctx.set_panic_data(panic_data).await;
+                self.handle_task_panic(&ctx).await;
+            }
+        }
+    }
+
+    /// Sends the accumulated response after the hook chain when auto-flush is enabled.
+    ///
+    /// Nothing is sent when the context was aborted, a reply was already sent,
+    /// or the response is empty. A failed send is dropped; hooks that need the
+    /// error call `Context::send_response` themselves.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Context` - The context of the finished request.
+    async fn flush_response(&self, ctx: &Context) {
+        if ctx.get_aborted().await || ctx.get_replied().await {
+            return;
+        }
+        let response: Response = ctx.get_response().await;
+        if response.get_data().is_empty() {
+            return;
+        }
+        let _: ResponseResult = ctx.send(response.get_data().clone()).await;
+    }
+
+    /// Executes the decode error hooks for a context whose request failed to decode.
+    ///
+    /// Runs inside the guarded chain, so the hooks share its panic handling and
+    /// deadline. Without registered hooks, the error goes to the read error hooks.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Context` - The context carrying the `RequestError::DecodeError`.
+    async fn handle_decode_error(&self, ctx: &Context) {
+        let decode_error: ServerHookList = self.read().await.get_decode_error().clone();
+        if decode_error.is_empty() {
+            self.handle_read_error(ctx).await;
+            return;
+        }
+        for hook in decode_error.iter() {
+            hook(ctx).await;
+            if ctx.get_aborted().await {
+                return;
+            }
+        }
+    }
+
+    /// Executes the read error hooks for a context carrying a request error.
+    ///
+    /// This is where request errors without a dedicated hook end up. Without
+    /// registered read error hooks, the error is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Context` - The context carrying the `RequestError`.
+    async fn handle_read_error(&self, ctx: &Context) {
+        let read_error: ServerHookList = self.read().await.get_read_error().clone();
+        if read_error.is_empty() {
+            return;
+        }
+        for hook in read_error.iter() {
+            hook(ctx).await;
+            if ctx.get_aborted().await {
+                return;
+            }
+        }
+    }
+
+    /// Executes the request timeout hooks for a context whose hook chain was cancelled.
+    ///
+    /// The context is already aborted, so every registered hook runs. Without
+    /// registered hooks, the error goes to the read error hooks. The hooks get the
+    /// same deadline as the chain they replace and run inside a `CatchUnwind` guard;
+    /// a panic among them is reported to the task panic hooks.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Context` - The context carrying the `RequestError::Timeout`.
+    /// - `Duration` - The deadline that expired, reused for the hooks.
+    async fn handle_request_timeout(&self, ctx: &Context, duration: Duration) {
+        let request_timeout: ServerHookList = self.read().await.get_request_timeout().clone();
+        let hooks: Pin<Box<dyn Future<Output = ()> + Send + '_>> = Box::pin(async {
+            if request_timeout.is_empty() {
+                self.handle_read_error(ctx).await;
+                return;
+            }
+            for hook in request_timeout.iter() {
+                hook(ctx).await;
+            }
+        });
+        if let Ok(Err(panic_data)) = timeout(duration, CatchUnwind::new(hooks)).await {
+            ctx.set_panic_data(panic_data).await;
+            self.handle_task_panic(ctx).await;
+        }
+    }
+
+    /// Executes the task panic hooks for a context whose handler panicked.
+    ///
+    /// The hooks run inside their own `CatchUnwind` guard: a panic in a task panic
+    /// hook ends the remaining hooks and is not reported again, so it cannot take
+    /// down the handler task or release its slot early.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Context` - The context carrying the captured panic data.
+    async fn handle_task_panic(&self, ctx: &Context) {
+        let task_panic: ServerHookList = self.read().await.get_task_panic().clone();
+        let hooks: Pin<Box<dyn Future<Output = ()> + Send + '_>> = Box::pin(async {
+            for hook in task_panic.iter() {
+                hook(ctx).await;
+                if ctx.get_aborted().await {
+                    return;
+                }
+            }
+        });
+        let _: Result<(), PanicData> = CatchUnwind::new(hooks).await;
+    }
+
+    /// Dispatches one received datagram to the rate limited, read error or request handlers.
+    ///
+    /// With correlation ID framing enabled, the ID is split off the payload here and
+    /// carried to the handler's context.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ReceiveLoopState` - The state of the receive loop.
+    /// - `ReceivedDatagram` - The datagram metadata.
+    /// - `Request` - The datagram payload.
+    async fn dispatch_datagram(
+        &self,
+        receive_state: &ReceiveLoopState,
+        datagram: ReceivedDatagram,
+        data: Request,
+    ) {
+        let local_addr: Option<SocketAddr> = match (datagram.local_ip, receive_state.socket_addr) {
+            (Some(ip), Some(addr)) => Some(SocketAddr::new(ip, addr.port())),
+            _ => receive_state.socket_addr,
+        };
+        let mut state: HandlerState = receive_state.handler_state(local_addr);
+        let (correlation_id, data): (Option<u32>, Request) = match receive_state
+            .correlation_ids
+            .then(|| split_correlation_id(&data))
+            .flatten()
+        {
+            Some((id, payload)) => (Some(id), payload),
+            None => (None, data),
+        };
+        state.set_correlation_id(correlation_id);
+        if let Some(rate_limiter) = receive_state.rate_limiter.as_ref()
+            && !rate_limiter.check(datagram.client_addr.ip())
+        {
+            self.spawn_rate_limited_handler(rate_limiter, state, data, datagram.client_addr)
+                .await;
+            return;
+        }
+        if receive_state.correlation_ids && correlation_id.is_none() {
+            self.spawn_read_error_handler(
+                &receive_state.read_error_hooks,
+                state,
+                RequestError::MissingCorrelationId,
+                Some(datagram.client_addr),
+            )
+            .await;
+            return;
+        }
+        if datagram.truncated && receive_state.policy == TruncationPolicy::Drop {
+            self.spawn_read_error_handler(
+                &receive_state.read_error_hooks,
+                state,
+                RequestError::BufferTooSmall,
+                Some(datagram.client_addr),
+            )
+            .await;
+            return;
+        }
+        state.set_truncated(datagram.truncated);
+        self.spawn_request_handler(state, data, datagram.client_addr)
+            .await;
+    }
+
+    /// Splits a buffer coalesced by `UDP_GRO` into its datagrams and dispatches each one.
+    ///
+    /// Buffers that were not coalesced are dispatched unchanged. Segments share the
+    /// received buffer without copying; only the last one can be short or truncated.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ReceiveLoopState` - The state of the receive loop.
+    /// - `ReceivedDatagram` - The metadata of the received buffer.
+    /// - `Request` - The received payload.
+    async fn dispatch_segments(
+        &self,
+        receive_state: &ReceiveLoopState,
+        datagram: ReceivedDatagram,
+        data: Request,
+    ) {
+        let segment_size: usize = match datagram.segment_size {
+            Some(segment_size) if segment_size < data.len() => segment_size,
+            _ => return self.dispatch_datagram(receive_state, datagram, data).await,
+        };
+        let mut start: usize = 0;
+        while start < data.len() {
+            let end: usize = (start + segment_size).min(data.len());
+            let segment: Request = data.slice(start..end);
+            let segment_datagram: ReceivedDatagram = ReceivedDatagram {
+                len: segment.len(),
+                datagram_len: segment.len(),
+                truncated: datagram.truncated && end == data.len(),
+                segment_size: None,
+                ..datagram
+            };
+            start = end;
+            self.dispatch_datagram(receive_state, segment_datagram, segment)
+                .await;
+        }
+    }
+
+    /// Receives datagrams from the socket and dispatches them to the request handlers.
+    ///
+    /// Datagrams larger than the receive buffer are handled according to the
+    /// configured `TruncationPolicy`. With a batch size above one, up to that many
+    /// datagrams are received per call into a pool of buffers, except under
+    /// `TruncationPolicy::Grow`, which needs to peek at each datagram. With GRO
+    /// enabled the receive buffer holds at least `MAX_DATAGRAM_SIZE` bytes so a
+    /// coalesced buffer is not cut short. Consecutive receive errors delay the
+    /// next receive, see `dispatch_receive_error`.
+    ///
+    /// # Arguments
+    ///
+    /// - `ReceiveLoopState` - The state of the receive loop.
+    /// - `ServerConfigData` - The server configuration.
+    async fn receive_loop(&self, receive_state: ReceiveLoopState, server_config: ServerConfigData) {
+        let policy: TruncationPolicy = receive_state.policy;
+        let socket: ArcUdpSocket = receive_state.socket.clone();
+        let batch_size: usize = server_config.get_batch_size();
+        let buffer_size: usize = if server_config.get_gro() {
+            server_config.get_buffer_size().max(MAX_DATAGRAM_SIZE)
+        } else {
+            server_config.get_buffer_size()
+        };
+        let mut backoff: Duration = Duration::ZERO;
+        if batch_size > 1 && policy != TruncationPolicy::Grow {
+            let mut batch: RecvBatch = RecvBatch::new(batch_size, buffer_size);
+            loop {
+                match recv_batch(&socket, &mut batch).await {
+                    Ok(_) => {
+                        backoff = Duration::ZERO;
+                        for index in 0..batch.len() {
+                            let (datagram, data): (ReceivedDatagram, Request) = batch.take(index);
+                            self.dispatch_segments(&receive_state, datagram, data).await;
+                        }
+                    }
+                    Err(error) => {
+                        self.dispatch_receive_error(&receive_state, error, &mut backoff)
+                            .await
+                    }
+                }
+            }
+        }
+        let mut pool: BufferPool = BufferPool::new(buffer_size);
+        loop {
+            match recv_datagram(&socket, &mut pool, policy).await {
+                Ok(datagram) => {
+                    backoff = Duration::ZERO;
+                    let data: Request = pool.take(datagram.len);
+                    self.dispatch_segments(&receive_state, datagram, data).await;
+                }
+                Err(error) => {
+                    self.dispatch_receive_error(&receive_state, error, &mut backoff)
+                        .await
+                }
+            }
+        }
+    }
+
+    /// Dispatches a receive failure to the read error handlers.
+    ///
+    /// A single error is dispatched without delay. From the second consecutive error
+    /// on, the loop sleeps before receiving again, starting at
+    /// `MIN_RECEIVE_ERROR_BACKOFF` and doubling up to `MAX_RECEIVE_ERROR_BACKOFF`,
+    /// so a persistent error neither spins nor floods the read error hooks. The
+    /// receive loop resets the backoff after the next successful receive.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ReceiveLoopState` - The state of the receive loop.
+    /// - `std::io::Error` - The receive error.
+    /// - `&mut Duration` - The current backoff, zero when the previous receive succeeded.
+    async fn dispatch_receive_error(
+        &self,
+        receive_state: &ReceiveLoopState,
+        error: std::io::Error,
+        backoff: &mut Duration,
+    ) {
+        let state: HandlerState = receive_state.handler_state(receive_state.socket_addr);
+        self.spawn_read_error_handler(
+            &receive_state.read_error_hooks,
+            state,
+            RequestError::from(error),
+            None,
+        )
+        .await;
+        if !backoff.is_zero() {
+            sleep(*backoff).await;
+        }
+        *backoff = backoff
+            .saturating_mul(2)
+            .clamp(MIN_RECEIVE_ERROR_BACKOFF, MAX_RECEIVE_ERROR_BACKOFF);
+    }
+
+    /// Runs a receive loop on a dedicated thread with its own current-thread runtime.
+    ///
+    /// The thread is pinned to the worker's core and the socket is registered with
+    /// the worker runtime before the thread starts, so a registration failure is
+    /// returned to the caller. The loop and the handlers it spawns stay on that thread.
+    /// The runtime is kept alive after shutdown is requested until the server has
+    /// finished draining, so in-flight handlers on it can complete.
+    ///
+    /// # Arguments
+    ///
+    /// - `PinnedWorker` - The socket and shared state for the worker.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), ServerError>` - `Ok` once the thread is started.
+    fn spawn_pinned_worker(&self, worker: PinnedWorker) -> Result<(), ServerError> {
+        let runtime: Runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|error: std::io::Error| ServerError::Other(error.to_string()))?;
+        let server: Server = self.clone();
+        let PinnedWorker {
+            socket,
+            core,
+            server_config,
+            tracker,
+            read_error_hooks,
+            rate_limiter,
+            sessions,
+            mut shutdown_receiver,
+            mut wait_receiver,
+        } = worker;
+        let socket: UdpSocket = {
+            let _guard: EnterGuard<'_> = runtime.enter();
+            UdpSocket::from_std(socket)
+                .map_err(|error: std::io::Error| ServerError::Other(error.to_string()))?
+        };
+        std::thread::Builder::new()
+            .name(format!("udp-worker-{core}"))
+            .spawn(move || {
+                pin_current_thread(core);
+                runtime.block_on(async move {
+                    let receive_state: ReceiveLoopState = ReceiveLoopState::new(
+                        Arc::new(socket),
+                        &server_config,
+                        tracker,
+                        read_error_hooks,
+                        rate_limiter,
+                        sessions,
+                    );
+                    let receive_loop: JoinHandle<()> = tokio::spawn(async move {
+                        server.receive_loop(receive_state, server_config).await;
+                    });
+                    let _: Result<(), tokio::sync::watch::error::RecvError> =
+                        shutdown_receiver.changed().await;
+                    receive_loop.abort();
+                    let _: Result<(), tokio::sync::watch::error::RecvError> =
+                        wait_receiver.changed().await;
+                });
+            })
+            .map_err(|error: std::io::Error| ServerError::Other(error.to_string()))?;
+        Ok(())
+    }
+
+    /// Starts the server, binds to the configured address, and begins listening for requests.
+    ///
+    /// This is the main entry point to launch the server. It will create a UDP socket
+    /// for every configured bind address and run one receive loop per socket, all
+    /// sharing the same hook chain.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a `ServerControlHook` on success.
+    /// Returns an error if the server fails to start, after stopping every task
+    /// and worker it had started.
+    pub async fn run(&self) -> Result<ServerControlHook, ServerError> {
+        install_panic_location_hook();
+        let sockets: Vec<UdpSocket> = self.create_udp_sockets().await?;
+        let mut local_addrs: Vec<SocketAddr> = sockets
+            .iter()
+            .filter_map(|socket: &UdpSocket| socket.local_addr().ok())
+            .collect();
+        local_addrs.dedup();
+        let (wait_sender, wait_receiver) = channel(());
+        let (shutdown_sender, mut shutdown_receiver) = channel(ShutdownMode::Immediate);
+        let server_config: ServerConfigData = self.read().await.get_server_config().clone();
+        let shutdown_timeout: Duration = server_config.get_shutdown_timeout();
+        let tracker: TaskTracker = TaskTracker::new(
+            server_config.get_max_in_flight(),
+            server_config.get_overload_policy(),
+        );
+        let read_error_hooks: TaskTracker = TaskTracker::new(
+            Some(server_config.get_max_read_error_in_flight()),
+            OverloadPolicy::DropNewest,
+        );
+        let rate_limiter: Option<RateLimiter> =
+            server_config.get_rate_limit().map(RateLimiter::new);
+        let pin_workers: bool = server_config.get_pin_workers();
+        let available_cores: usize = std::thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1);
+        let sessions: SessionStore = {
+            let server: RwLockReadGuard<'_, ServerData> = self.read().await;
+            SessionStore::new(
+                &server_config,
+                server.get_session_created().clone(),
+                server.get_session_expired().clone(),
+                server.get_state().clone(),
+            )
+        };
+        let (sockets, pinned_sockets): (Vec<UdpSocket>, Vec<std::net::UdpSocket>) = if pin_workers {
+            let pinned_sockets: Vec<std::net::UdpSocket> = sockets
+                .into_iter()
+                .map(UdpSocket::into_std)
+                .collect::<Result<_, std::io::Error>>()
+                .map_err(|error: std::io::Error| ServerError::Other(error.to_string()))?;
+            (Vec::new(), pinned_sockets)
+        } else {
+            (sockets, Vec::new())
+        };
+        let mut accept_requests: Vec<JoinHandle<()>> =
+            vec![tokio::spawn(sweep_sessions(sessions.clone()))];
+        for socket in sockets {
+            let server: Server = self.clone();
+            let server_config: ServerConfigData = server_config.clone();
+            let receive_state: ReceiveLoopState = ReceiveLoopState::new(
+                Arc::new(socket),
+                &server_config,
+                tracker.clone(),
+                read_error_hooks.clone(),
+                rate_limiter.clone(),
+                sessions.clone(),
+            );
+            accept_requests.push(tokio::spawn(async move {
+                server.receive_loop(receive_state, server_config).await;
+            }));
+        }
+        for (index, socket) in pinned_sockets.into_iter().enumerate() {
+            let started: Result<(), ServerError> = self.spawn_pinned_worker(PinnedWorker {
+                socket,
+                core: index % available_cores,
+                server_config: server_config.clone(),
+                tracker: tracker.clone(),
+                read_error_hooks: read_error_hooks.clone(),
+                rate_limiter: rate_limiter.clone(),
+                sessions: sessions.clone(),
+                shutdown_receiver: shutdown_receiver.clone(),
+                wait_receiver: wait_receiver.clone(),
+            });
+            if let Err(error) = started {
+                for accept_request in accept_requests.iter() {
+                    accept_request.abort();
+                }
+                let _: Result<(), tokio::sync::watch::error::SendError<ShutdownMode>> =
+                    shutdown_sender.send(ShutdownMode::Immediate);
+                let _: Result<(), tokio::sync::watch::error::SendError<()>> = wait_sender.send(());
+                return Err(error);
+            }
+        }
+        let wait_receiver_graceful: Receiver<()> = wait_receiver.clone();
+        let wait_hook: SharedAsyncTaskFactory<()> = Arc::new(move || {
             let mut wait_receiver_clone: Receiver<()> = wait_receiver.clone();
             Box::pin(async move {
                 let _: Result<(), tokio::sync::watch::error::RecvError> =
                     wait_receiver_clone.changed().await;
             })
         });
-        let shutdown_sender_arc: Arc<Sender<()>> = Arc::new(shutdown_sender);
+        let shutdown_sender_arc: Arc<Sender<ShutdownMode>> = Arc::new(shutdown_sender);
+        let shutdown_sender_graceful: Arc<Sender<ShutdownMode>> = shutdown_sender_arc.clone();
         let shutdown_hook: SharedAsyncTaskFactory<()> = Arc::new(move || {
-            let shutdown_sender_clone: Arc<Sender<()>> = shutdown_sender_arc.clone();
+            let shutdown_sender_clone: Arc<Sender<ShutdownMode>> = shutdown_sender_arc.clone();
+            Box::pin(async move {
+                let _: Result<(), tokio::sync::watch::error::SendError<ShutdownMode>> =
+                    shutdown_sender_clone.send(ShutdownMode::Immediate);
+            })
+        });
+        let graceful_shutdown_hook: SharedAsyncTaskFactory<()> = Arc::new(move || {
+            let shutdown_sender_clone: Arc<Sender<ShutdownMode>> = shutdown_sender_graceful.clone();
+            let mut wait_receiver_clone: Receiver<()> = wait_receiver_graceful.clone();
             Box::pin(async move {
-                let _: Result<(), tokio::sync::watch::error::SendError<()>> =
-                    shutdown_sender_clone.send(());
+                let _: Result<(), tokio::sync::watch::error::SendError<ShutdownMode>> =
+                    shutdown_sender_clone.send(ShutdownMode::Graceful);
+                let _: Result<(), tokio::sync::watch::error::RecvError> =
+                    wait_receiver_clone.changed().await;
             })
         });
+        let in_flight_tracker: TaskTracker = tracker.clone();
+        let in_flight_hook: Arc<dyn Fn() -> usize + Send + Sync> =
+            Arc::new(move || in_flight_tracker.len());
+        let dropped_tracker: TaskTracker = tracker.clone();
+        let dropped_hook: Arc<dyn Fn() -> u64 + Send + Sync> =
+            Arc::new(move || dropped_tracker.dropped());
+        let read_errors_dropped_tracker: TaskTracker = read_error_hooks.clone();
+        let read_errors_dropped_hook: Arc<dyn Fn() -> u64 + Send + Sync> =
+            Arc::new(move || read_errors_dropped_tracker.dropped());
+        let sessions_len: SessionStore = sessions.clone();
+        let sessions_hook: Arc<dyn Fn() -> usize + Send + Sync> =
+            Arc::new(move || sessions_len.len());
+        let rate_limited_limiter: Option<RateLimiter> = rate_limiter.clone();
+        let rate_limited_hook: Arc<dyn Fn() -> u64 + Send + Sync> = Arc::new(move || {
+            rate_limited_limiter
+                .as_ref()
+                .map_or(0, |rate_limiter: &RateLimiter| rate_limiter.rejected())
+        });
+        let rate_limited_hooks_limiter: Option<RateLimiter> = rate_limiter.clone();
+        let rate_limited_hooks_dropped_hook: Arc<dyn Fn() -> u64 + Send + Sync> =
+            Arc::new(move || {
+                rate_limited_hooks_limiter
+                    .as_ref()
+                    .map_or(0, |rate_limiter: &RateLimiter| {
+                        rate_limiter.get_hooks().dropped()
+                    })
+            });
+        let rate_limited_hooks: Option<TaskTracker> = rate_limiter
+            .as_ref()
+            .map(|rate_limiter: &RateLimiter| rate_limiter.get_hooks().clone());
         tokio::spawn(async move {
             let _: Result<(), tokio::sync::watch::error::RecvError> =
                 shutdown_receiver.changed().await;
-            accept_requests.abort();
+            for accept_request in accept_requests.iter() {
+                accept_request.abort();
+            }
+            sessions.clear();
+            if *shutdown_receiver.borrow() == ShutdownMode::Graceful {
+                let _: Result<(), Elapsed> = timeout(shutdown_timeout, async {
+                    tracker.wait_idle().await;
+                    read_error_hooks.wait_idle().await;
+                    if let Some(rate_limited_hooks) = &rate_limited_hooks {
+                        rate_limited_hooks.wait_idle().await;
+                    }
+                    sessions.get_hooks().wait_idle().await;
+                })
+                .await;
+                tracker.abort_all();
+                read_error_hooks.abort_all();
+                sessions.get_hooks().abort_all();
+                if let Some(rate_limited_hooks) = &rate_limited_hooks {
+                    rate_limited_hooks.abort_all();
+                }
+            }
             let _: Result<(), tokio::sync::watch::error::SendError<()>> = wait_sender.send(());
         });
         let mut server_control_hook: ServerControlHook = ServerControlHook::default();
         server_control_hook.set_shutdown_hook(shutdown_hook);
+        server_control_hook.set_graceful_shutdown_hook(graceful_shutdown_hook);
         server_control_hook.set_wait_hook(wait_hook);
+        server_control_hook.set_in_flight_hook(in_flight_hook);
+        server_control_hook.set_dropped_hook(dropped_hook);
+        server_control_hook.set_read_errors_dropped_hook(read_errors_dropped_hook);
+        server_control_hook.set_rate_limited_hook(rate_limited_hook);
+        server_control_hook.set_rate_limited_hooks_dropped_hook(rate_limited_hooks_dropped_hook);
+        server_control_hook.set_sessions_hook(sessions_hook);
+        server_control_hook.set_local_addrs(local_addrs);
         Ok(server_control_hook)
     }
 }
+
+/// Implementation of methods for `ReceiveLoopState`.
+impl ReceiveLoopState {
+    /// Creates the state of a receive loop for one socket.
+    ///
+    /// Must be called inside the runtime the loop runs on, since the reply batcher
+    /// spawns its send task there.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcUdpSocket` - The socket the loop receives from.
+    /// - `&ServerConfigData` - The server configuration.
+    /// - `TaskTracker` - The tracker owning the spawned request handlers.
+    /// - `TaskTracker` - The tracker running the read error hooks.
+    /// - `Option<RateLimiter>` - The shared rate limiter, if configured.
+    /// - `SessionStore` - The sessions of the server run.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The receive loop state.
+    fn new(
+        socket: ArcUdpSocket,
+        server_config: &ServerConfigData,
+        tracker: TaskTracker,
+        read_error_hooks: TaskTracker,
+        rate_limiter: Option<RateLimiter>,
+        sessions: SessionStore,
+    ) -> Self {
+        let reply_batcher: Option<ReplyBatcher> = (server_config.get_batch_size() > 1)
+            .then(|| ReplyBatcher::new(socket.clone(), server_config.get_batch_size()));
+        Self {
+            socket_addr: socket.local_addr().ok(),
+            socket,
+            policy: server_config.get_truncation_policy(),
+            tracker,
+            read_error_hooks,
+            rate_limiter,
+            reply_batcher,
+            correlation_ids: server_config.get_correlation_ids(),
+            sessions,
+        }
+    }
+
+    /// Creates the handler state for a datagram received by this loop.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<SocketAddr>` - The local address the datagram arrived on.
+    ///
+    /// # Returns
+    ///
+    /// - `HandlerState` - The handler state.
+    fn handler_state(&self, local_addr: Option<SocketAddr>) -> HandlerState {
+        let mut state: HandlerState =
+            HandlerState::new(self.socket.clone(), local_addr, self.tracker.clone());
+        state.set_reply_batcher(self.reply_batcher.clone());
+        state.set_sessions(Some(self.sessions.clone()));
+        state
+    }
+}
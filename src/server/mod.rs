@@ -1,6 +1,10 @@
+mod r#enum;
+mod r#fn;
 mod r#impl;
 mod r#struct;
 
+pub(crate) use {r#enum::*, r#fn::*};
+
 pub use r#struct::*;
 
 use super::*;
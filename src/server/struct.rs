@@ -12,10 +12,28 @@ pub(crate) struct ServerData {
     pub(super) server_config: ServerConfigData,
     /// A collection of request hooks that are invoked for each incoming request.
     pub(super) hook: ServerHookList,
+    /// The middleware wrapped around the request hooks, outermost first.
+    pub(super) middleware: ServerMiddlewareList,
     /// A collection of task panic hooks that are invoked when a panic occurs during request processing.
     pub(super) task_panic: ServerHookList,
     /// The read error hooks for server operations.
     pub(super) read_error: ServerHookList,
+    /// The hooks invoked for datagrams rejected by the rate limiter.
+    pub(super) rate_limited: ServerHookList,
+    /// The routed handler chains run after the request hooks.
+    pub(super) router: Router,
+    /// The codec that decodes every request before the hooks run, if any.
+    pub(super) codec: Option<SharedCodec>,
+    /// The hooks invoked for requests the codec fails to decode.
+    pub(super) decode_error: ServerHookList,
+    /// The hooks invoked for requests whose hook chain exceeded its deadline.
+    pub(super) request_timeout: ServerHookList,
+    /// The typed application state shared with every context.
+    pub(super) state: SharedStateStore,
+    /// The hooks invoked when a session is created.
+    pub(super) session_created: ServerHookList,
+    /// The hooks invoked when a session expires.
+    pub(super) session_expired: ServerHookList,
 }
 
 /// The primary server structure that provides a thread-safe interface to the server's state.
@@ -25,3 +43,48 @@ pub(crate) struct ServerData {
 /// configuration and state across different threads and asynchronous tasks.
 #[derive(Clone)]
 pub struct Server(pub(super) ArcRwLock<ServerData>);
+
+/// The per-socket state shared by every datagram of one receive loop.
+#[derive(Clone)]
+pub(crate) struct ReceiveLoopState {
+    /// The socket the loop receives from.
+    pub(super) socket: ArcUdpSocket,
+    /// The address the socket is bound to.
+    pub(super) socket_addr: Option<SocketAddr>,
+    /// The policy applied to datagrams larger than the receive buffer.
+    pub(super) policy: TruncationPolicy,
+    /// The tracker owning the spawned request handlers.
+    pub(super) tracker: TaskTracker,
+    /// The tracker running the read error hooks, bounded apart from the handlers.
+    pub(super) read_error_hooks: TaskTracker,
+    /// The rate limiter shared by all receive loops, if configured.
+    pub(super) rate_limiter: Option<RateLimiter>,
+    /// The batcher coalescing replies sent from this socket, if batching is enabled.
+    pub(super) reply_batcher: Option<ReplyBatcher>,
+    /// Whether datagrams are framed with a correlation ID.
+    pub(super) correlation_ids: bool,
+    /// The sessions of the server run.
+    pub(super) sessions: SessionStore,
+}
+
+/// A socket and the shared server state handed to a pinned worker thread.
+pub(crate) struct PinnedWorker {
+    /// The bound socket, not yet registered with a runtime.
+    pub(super) socket: std::net::UdpSocket,
+    /// The index of the core the worker thread is pinned to.
+    pub(super) core: usize,
+    /// The server configuration.
+    pub(super) server_config: ServerConfigData,
+    /// The tracker owning the spawned request handlers.
+    pub(super) tracker: TaskTracker,
+    /// The tracker running the read error hooks, bounded apart from the handlers.
+    pub(super) read_error_hooks: TaskTracker,
+    /// The rate limiter shared by all receive loops, if configured.
+    pub(super) rate_limiter: Option<RateLimiter>,
+    /// The sessions of the server run.
+    pub(super) sessions: SessionStore,
+    /// Signals that the server is shutting down.
+    pub(super) shutdown_receiver: Receiver<ShutdownMode>,
+    /// Signals that the server has finished draining.
+    pub(super) wait_receiver: Receiver<()>,
+}
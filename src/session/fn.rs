@@ -0,0 +1,56 @@
+use super::*;
+
+/// Runs the session expired hooks for each expired session.
+///
+/// Each session gets its own context with an empty request, addressed to the
+/// session's client, so hooks can still send a final datagram. Every chain runs
+/// in its own task on the store's hook tracker, inside a `CatchUnwind` guard, so
+/// a panicking or slow hook neither stops the sweeper nor delays other sessions.
+/// The tracker is drained on graceful shutdown.
+///
+/// # Arguments
+///
+/// - `&SessionStore` - The store the sessions were removed from.
+/// - `Vec<Session>` - The expired sessions.
+pub(crate) fn expire_sessions(store: &SessionStore, sessions: Vec<Session>) {
+    let expired: &ServerHookList = store.get_expired();
+    if expired.is_empty() {
+        return;
+    }
+    for session in sessions {
+        let expired: ServerHookList = expired.clone();
+        let session_store: SessionStore = store.clone();
+        let run: SendableAsyncTask<()> = Box::pin(async move {
+            let ctx: Context = session_store.session_context(session).await;
+            for hook in expired.iter() {
+                hook(&ctx).await;
+                if ctx.get_aborted().await {
+                    break;
+                }
+            }
+        });
+        let location: PanicLocationSlot = Arc::new(Mutex::new(None));
+        store.get_hooks().try_spawn(async move {
+            let _: Result<(), PanicData> =
+                PANIC_LOCATION.scope(location, CatchUnwind::new(run)).await;
+        });
+    }
+}
+
+/// Periodically removes idle sessions and runs the session expired hooks.
+///
+/// Sessions are checked twice per idle timeout.
+///
+/// # Arguments
+///
+/// - `SessionStore` - The store to sweep.
+pub(crate) async fn sweep_sessions(store: SessionStore) {
+    let period: Duration = (store.get_idle_timeout() / 2).max(MIN_SESSION_SWEEP_INTERVAL);
+    let mut interval: Interval = interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        let sessions: Vec<Session> = store.sweep();
+        expire_sessions(&store, sessions);
+    }
+}
@@ -0,0 +1,446 @@
+use super::*;
+
+/// The shortest interval between two sweeps of idle sessions.
+pub(crate) const MIN_SESSION_SWEEP_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Implementation of methods for `Session`.
+impl Session {
+    /// Creates a session with no attributes.
+    ///
+    /// # Arguments
+    ///
+    /// - `SocketAddr` - The client address.
+    /// - `ArcUdpSocket` - The socket the first datagram arrived on.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The session.
+    pub(crate) fn new(client_addr: SocketAddr, socket: ArcUdpSocket) -> Self {
+        Self(Arc::new(SessionData {
+            client_addr,
+            socket,
+            created_at: Instant::now(),
+            attributes: RwLock::new(HashMap::new()),
+        }))
+    }
+
+    /// Gets the client address the session belongs to.
+    ///
+    /// # Returns
+    ///
+    /// - `SocketAddr` - The client address.
+    pub fn get_client_addr(&self) -> SocketAddr {
+        self.0.client_addr
+    }
+
+    /// Gets the socket the session's first datagram arrived on.
+    ///
+    /// # Returns
+    ///
+    /// - `ArcUdpSocket` - The socket.
+    pub fn get_socket(&self) -> ArcUdpSocket {
+        self.0.socket.clone()
+    }
+
+    /// Gets when the session was created.
+    ///
+    /// # Returns
+    ///
+    /// - `Instant` - The creation time.
+    pub fn get_created_at(&self) -> Instant {
+        self.0.created_at
+    }
+
+    /// Sets an attribute in the session.
+    ///
+    /// # Arguments
+    ///
+    /// - `K` - The key of the attribute to set.
+    /// - `V` - The value of the attribute.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to the modified session.
+    pub async fn set_attribute<K, V>(&self, key: K, value: V) -> &Self
+    where
+        K: AsRef<str>,
+        V: AnySendSyncClone,
+    {
+        self.0
+            .attributes
+            .write()
+            .await
+            .insert(key.as_ref().to_owned(), Arc::new(value));
+        self
+    }
+
+    /// Attempts to retrieve a specific attribute by its key.
+    ///
+    /// # Arguments
+    ///
+    /// - `K` - The key of the attribute to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<V>` - The attribute value if it exists and can be cast to the specified type.
+    pub async fn try_get_attribute<V, K>(&self, key: K) -> Option<V>
+    where
+        V: AnySendSyncClone,
+        K: AsRef<str>,
+    {
+        self.0
+            .attributes
+            .read()
+            .await
+            .get(key.as_ref())
+            .and_then(|arc| arc.downcast_ref::<V>())
+            .cloned()
+    }
+
+    /// Retrieves a specific attribute by its key.
+    ///
+    /// # Arguments
+    ///
+    /// - `K` - The key of the attribute to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// - `V` - The attribute value.
+    ///
+    /// # Panics
+    ///
+    /// - If the attribute is not found.
+    pub async fn get_attribute<V, K>(&self, key: K) -> V
+    where
+        V: AnySendSyncClone,
+        K: AsRef<str>,
+    {
+        self.try_get_attribute(key).await.unwrap()
+    }
+
+    /// Removes an attribute from the session.
+    ///
+    /// # Arguments
+    ///
+    /// - `K` - The key of the attribute to remove.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to the modified session.
+    pub async fn remove_attribute<K>(&self, key: K) -> &Self
+    where
+        K: AsRef<str>,
+    {
+        self.0.attributes.write().await.remove(key.as_ref());
+        self
+    }
+
+    /// Clears all attributes from the session.
+    ///
+    /// # Returns
+    ///
+    /// - `&Self` - A reference to the modified session.
+    pub async fn clear_attributes(&self) -> &Self {
+        self.0.attributes.write().await.clear();
+        self
+    }
+}
+
+/// Implementation of methods for `SessionEntry`.
+impl SessionEntry {
+    /// Checks whether the session has been idle for longer than the timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `Duration` - The idle timeout.
+    /// - `Instant` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if the session has expired.
+    fn is_expired(&self, idle_timeout: Duration, now: Instant) -> bool {
+        now.saturating_duration_since(self.last_seen) >= idle_timeout
+    }
+}
+
+/// Implementation of methods for `SessionStore`.
+impl SessionStore {
+    /// Creates an empty session store.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ServerConfigData` - The server configuration.
+    /// - `ServerHookList` - The hooks invoked when a session is created.
+    /// - `ServerHookList` - The hooks invoked when a session expires.
+    /// - `SharedStateStore` - The typed application state handed to the session hook contexts.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The session store.
+    pub(crate) fn new(
+        server_config: &ServerConfigData,
+        created: ServerHookList,
+        expired: ServerHookList,
+        state: SharedStateStore,
+    ) -> Self {
+        Self(Arc::new(SessionStoreData {
+            idle_timeout: server_config.get_session_idle_timeout(),
+            max_sessions: server_config.get_max_sessions(),
+            sessions: Mutex::new(HashMap::new()),
+            live: AtomicUsize::new(0),
+            created,
+            expired,
+            state,
+            hooks: TaskTracker::new(None, OverloadPolicy::DropNewest),
+        }))
+    }
+
+    /// Locks the sessions, recovering from a poisoned lock.
+    ///
+    /// # Returns
+    ///
+    /// - `MutexGuard<'_, HashMap<SocketAddr, SessionEntry>>` - The locked sessions.
+    fn lock(&self) -> MutexGuard<'_, HashMap<SocketAddr, SessionEntry>> {
+        self.0.sessions.lock().unwrap_or_else(
+            |error: PoisonError<MutexGuard<'_, HashMap<SocketAddr, SessionEntry>>>| {
+                error.into_inner()
+            },
+        )
+    }
+
+    /// Gets the idle timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - How long a session may stay idle before it expires.
+    pub(crate) fn get_idle_timeout(&self) -> Duration {
+        self.0.idle_timeout
+    }
+
+    /// Gets the session created hooks.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerHookList` - The hooks invoked when a session is created.
+    pub(crate) fn get_created(&self) -> &ServerHookList {
+        &self.0.created
+    }
+
+    /// Gets the session expired hooks.
+    ///
+    /// # Returns
+    ///
+    /// - `&ServerHookList` - The hooks invoked when a session expires.
+    pub(crate) fn get_expired(&self) -> &ServerHookList {
+        &self.0.expired
+    }
+
+    /// Gets the tracker running the session expired hooks.
+    ///
+    /// # Returns
+    ///
+    /// - `&TaskTracker` - The tracker, drained on graceful shutdown.
+    pub(crate) fn get_hooks(&self) -> &TaskTracker {
+        &self.0.hooks
+    }
+
+    /// Creates the context the session created and expired hooks run on.
+    ///
+    /// The context has an empty request and is addressed to the session's client,
+    /// apart from any request context, so aborting it only ends the session hooks.
+    ///
+    /// # Arguments
+    ///
+    /// - `Session` - The session the hooks run for.
+    ///
+    /// # Returns
+    ///
+    /// - `Context` - The session context.
+    pub(crate) async fn session_context(&self, session: Session) -> Context {
+        let ctx: Context = Context::new(
+            session.get_socket(),
+            &Request::new(),
+            session.get_client_addr(),
+        );
+        ctx.set_state(self.0.state.clone()).await;
+        ctx.set_session(session).await;
+        ctx
+    }
+
+    /// Records the session count after the locked sessions were changed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HashMap<SocketAddr, SessionEntry>` - The locked sessions.
+    fn sync_live(&self, sessions: &HashMap<SocketAddr, SessionEntry>) {
+        self.0.live.store(sessions.len(), Ordering::Relaxed);
+    }
+
+    /// Gets the number of sessions, including idle ones not swept yet.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The session count.
+    pub(crate) fn len(&self) -> usize {
+        self.0.live.load(Ordering::Relaxed)
+    }
+
+    /// Records activity from a client, keeping its session alive.
+    ///
+    /// Sessions that have already expired are left for the sweeper. While no
+    /// session exists, this returns without taking the lock.
+    ///
+    /// # Arguments
+    ///
+    /// - `SocketAddr` - The client address.
+    pub(crate) fn touch(&self, client_addr: SocketAddr) {
+        if self.len() == 0 {
+            return;
+        }
+        let now: Instant = Instant::now();
+        let idle_timeout: Duration = self.0.idle_timeout;
+        if let Some(entry) = self.lock().get_mut(&client_addr)
+            && !entry.is_expired(idle_timeout, now)
+        {
+            entry.last_seen = now;
+        }
+    }
+
+    /// Gets the live session of a client, creating it when missing.
+    ///
+    /// When the session limit is reached, idle sessions are evicted first; if
+    /// every session is still active, no session is created.
+    ///
+    /// # Arguments
+    ///
+    /// - `SocketAddr` - The client address.
+    /// - `ArcUdpSocket` - The socket the datagram arrived on.
+    ///
+    /// # Returns
+    ///
+    /// - `SessionLookup` - The session and the sessions evicted on the way.
+    pub(crate) fn get_or_create(
+        &self,
+        client_addr: SocketAddr,
+        socket: ArcUdpSocket,
+    ) -> SessionLookup {
+        let mut sessions: MutexGuard<'_, HashMap<SocketAddr, SessionEntry>> = self.lock();
+        let lookup: SessionLookup = self.get_or_create_locked(&mut sessions, client_addr, socket);
+        self.sync_live(&sessions);
+        lookup
+    }
+
+    /// Gets the live session of a client, creating it when missing, with the sessions locked.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut HashMap<SocketAddr, SessionEntry>` - The locked sessions.
+    /// - `SocketAddr` - The client address.
+    /// - `ArcUdpSocket` - The socket the datagram arrived on.
+    ///
+    /// # Returns
+    ///
+    /// - `SessionLookup` - The session and the sessions evicted on the way.
+    fn get_or_create_locked(
+        &self,
+        sessions: &mut HashMap<SocketAddr, SessionEntry>,
+        client_addr: SocketAddr,
+        socket: ArcUdpSocket,
+    ) -> SessionLookup {
+        let now: Instant = Instant::now();
+        let idle_timeout: Duration = self.0.idle_timeout;
+        let mut expired: Vec<Session> = Vec::new();
+        if let Some(entry) = sessions.get_mut(&client_addr) {
+            if !entry.is_expired(idle_timeout, now) {
+                entry.last_seen = now;
+                return SessionLookup {
+                    session: Some(entry.session.clone()),
+                    created: false,
+                    expired,
+                };
+            }
+            if let Some(entry) = sessions.remove(&client_addr) {
+                expired.push(entry.session);
+            }
+        }
+        if let Some(max_sessions) = self.0.max_sessions
+            && sessions.len() >= max_sessions
+        {
+            sessions.retain(|_, entry: &mut SessionEntry| {
+                if entry.is_expired(idle_timeout, now) {
+                    expired.push(entry.session.clone());
+                    return false;
+                }
+                true
+            });
+            if sessions.len() >= max_sessions {
+                return SessionLookup {
+                    session: None,
+                    created: false,
+                    expired,
+                };
+            }
+        }
+        let session: Session = Session::new(client_addr, socket);
+        sessions.insert(
+            client_addr,
+            SessionEntry {
+                session: session.clone(),
+                last_seen: now,
+            },
+        );
+        SessionLookup {
+            session: Some(session),
+            created: true,
+            expired,
+        }
+    }
+
+    /// Removes the session of a client.
+    ///
+    /// # Arguments
+    ///
+    /// - `SocketAddr` - The client address.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Session>` - The removed session, if any.
+    pub(crate) fn remove(&self, client_addr: SocketAddr) -> Option<Session> {
+        let mut sessions: MutexGuard<'_, HashMap<SocketAddr, SessionEntry>> = self.lock();
+        let removed: Option<Session> = sessions
+            .remove(&client_addr)
+            .map(|entry: SessionEntry| entry.session);
+        self.sync_live(&sessions);
+        removed
+    }
+
+    /// Removes every session without running the session expired hooks.
+    ///
+    /// Called when the server run ends, so sessions do not outlive it.
+    pub(crate) fn clear(&self) {
+        let mut sessions: MutexGuard<'_, HashMap<SocketAddr, SessionEntry>> = self.lock();
+        sessions.clear();
+        self.sync_live(&sessions);
+    }
+
+    /// Removes every idle session.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Session>` - The expired sessions.
+    pub(crate) fn sweep(&self) -> Vec<Session> {
+        let now: Instant = Instant::now();
+        let idle_timeout: Duration = self.0.idle_timeout;
+        let mut expired: Vec<Session> = Vec::new();
+        let mut sessions: MutexGuard<'_, HashMap<SocketAddr, SessionEntry>> = self.lock();
+        sessions.retain(|_, entry: &mut SessionEntry| {
+            if entry.is_expired(idle_timeout, now) {
+                expired.push(entry.session.clone());
+                return false;
+            }
+            true
+        });
+        self.sync_live(&sessions);
+        expired
+    }
+}
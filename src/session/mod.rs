@@ -0,0 +1,9 @@
+mod r#fn;
+mod r#impl;
+mod r#struct;
+
+pub(crate) use {r#fn::*, r#impl::*};
+
+pub use r#struct::*;
+
+use super::*;
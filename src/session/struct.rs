@@ -0,0 +1,64 @@
+use super::*;
+
+/// Shared state behind a `Session`.
+pub(crate) struct SessionData {
+    /// The client address the session belongs to.
+    pub(super) client_addr: SocketAddr,
+    /// The socket the session's first datagram arrived on.
+    pub(super) socket: ArcUdpSocket,
+    /// When the session was created.
+    pub(super) created_at: Instant,
+    /// The custom attributes kept for the lifetime of the session.
+    pub(super) attributes: RwLock<ThreadSafeAttributeStore>,
+}
+
+/// Per-peer state that persists across datagrams from one client address.
+///
+/// Unlike the attributes of a `Context`, which are dropped after each request,
+/// session attributes live until the client has been idle for the configured
+/// session idle timeout.
+#[derive(Clone)]
+pub struct Session(pub(super) Arc<SessionData>);
+
+/// A session together with the time its client was last seen.
+#[derive(Clone)]
+pub(crate) struct SessionEntry {
+    /// The session.
+    pub(super) session: Session,
+    /// When a datagram from the client was last received.
+    pub(super) last_seen: Instant,
+}
+
+/// Shared state behind a `SessionStore`.
+pub(crate) struct SessionStoreData {
+    /// How long a session may stay idle before it expires.
+    pub(super) idle_timeout: Duration,
+    /// The maximum number of concurrent sessions; `None` means unbounded.
+    pub(super) max_sessions: Option<usize>,
+    /// The sessions keyed by client address.
+    pub(super) sessions: Mutex<HashMap<SocketAddr, SessionEntry>>,
+    /// The number of sessions, kept in step with `sessions` so idle servers skip the lock.
+    pub(super) live: AtomicUsize,
+    /// The hooks invoked when a session is created.
+    pub(super) created: ServerHookList,
+    /// The hooks invoked when a session expires.
+    pub(super) expired: ServerHookList,
+    /// The typed application state handed to the session hook contexts.
+    pub(super) state: SharedStateStore,
+    /// The tracker running the session expired hooks.
+    pub(super) hooks: TaskTracker,
+}
+
+/// The sessions of one server run, keyed by client address.
+#[derive(Clone)]
+pub(crate) struct SessionStore(pub(super) Arc<SessionStoreData>);
+
+/// The outcome of looking up or creating a session.
+pub(crate) struct SessionLookup {
+    /// The session, or `None` when the session limit was reached.
+    pub(crate) session: Option<Session>,
+    /// Whether the session was created by this lookup.
+    pub(crate) created: bool,
+    /// The idle sessions evicted to make room for the new one.
+    pub(crate) expired: Vec<Session>,
+}
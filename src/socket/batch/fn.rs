@@ -0,0 +1,240 @@
+use super::*;
+
+/// Performs a single non-blocking `recvmmsg` into the buffer pool.
+///
+/// Datagrams whose sender address family is unsupported are skipped.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to receive from.
+/// - `&mut RecvBatch` - The buffer pool to fill.
+///
+/// # Returns
+///
+/// - `std::io::Result<usize>` - The number of datagrams received.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn try_recv_batch(socket: &UdpSocket, batch: &mut RecvBatch) -> std::io::Result<usize> {
+    let count: usize = batch.bufs.len();
+    let mut iovecs: Vec<libc::iovec> = batch
+        .bufs
+        .iter_mut()
+        .map(|pool: &mut BufferPool| {
+            let buf: &mut [MaybeUninit<u8>] = pool.buffer();
+            libc::iovec {
+                iov_base: buf.as_mut_ptr() as *mut libc::c_void,
+                iov_len: buf.len(),
+            }
+        })
+        .collect();
+    let mut msgs: Vec<libc::mmsghdr> = (0..count)
+        .map(|index: usize| {
+            // SAFETY: an all-zero `mmsghdr` is a valid empty header.
+            let mut msg: libc::mmsghdr = unsafe { zeroed() };
+            msg.msg_hdr.msg_name =
+                &mut batch.addrs[index] as *mut libc::sockaddr_storage as *mut libc::c_void;
+            msg.msg_hdr.msg_namelen = size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+            msg.msg_hdr.msg_iov = &mut iovecs[index];
+            msg.msg_hdr.msg_iovlen = 1;
+            msg.msg_hdr.msg_control = batch.controls[index].as_mut_ptr() as *mut libc::c_void;
+            msg.msg_hdr.msg_controllen = CONTROL_BUFFER_SIZE as _;
+            msg
+        })
+        .collect();
+    // SAFETY: every header points at live, correctly sized buffers owned by
+    // `batch` and `iovecs`, which outlive the call.
+    let received: libc::c_int = unsafe {
+        libc::recvmmsg(
+            socket.as_raw_fd(),
+            msgs.as_mut_ptr(),
+            count as _,
+            libc::MSG_TRUNC as _,
+            std::ptr::null_mut(),
+        )
+    };
+    if received < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    batch.datagrams.clear();
+    for (index, msg) in msgs.iter().enumerate().take(received as usize) {
+        let buf_len: usize = batch.bufs[index].get_buffer_size();
+        let datagram_len: usize = msg.msg_len as usize;
+        let mut storage: SockAddrStorage = SockAddrStorage::zeroed();
+        // SAFETY: `sockaddr_storage` is a valid view of `SockAddrStorage`, and the
+        // kernel reported the used length in `msg_namelen`.
+        let addr: SockAddr = unsafe {
+            *storage.view_as::<libc::sockaddr_storage>() = batch.addrs[index];
+            SockAddr::new(storage, msg.msg_hdr.msg_namelen)
+        };
+        let Some(client_addr) = addr.as_socket() else {
+            continue;
+        };
+        let control_len: usize = msg.msg_hdr.msg_controllen as _;
+        let local_ip: Option<IpAddr> =
+            parse_local_ip(batch.controls[index].as_bytes(), control_len);
+        let segment_size: Option<usize> =
+            parse_segment_size(batch.controls[index].as_bytes(), control_len);
+        batch.datagrams.push((
+            index,
+            ReceivedDatagram {
+                len: datagram_len.min(buf_len),
+                datagram_len,
+                client_addr,
+                local_ip,
+                truncated: msg.msg_hdr.msg_flags & libc::MSG_TRUNC != 0 || datagram_len > buf_len,
+                segment_size,
+            },
+        ));
+    }
+    Ok(batch.datagrams.len())
+}
+
+/// Receives up to one batch of datagrams, waiting for the socket to become readable.
+///
+/// On Linux and Android this is a single `recvmmsg` call; elsewhere a single
+/// datagram is received per call.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to receive from.
+/// - `&mut RecvBatch` - The buffer pool to fill.
+///
+/// # Returns
+///
+/// - `std::io::Result<usize>` - The number of datagrams received.
+pub(crate) async fn recv_batch(
+    socket: &UdpSocket,
+    batch: &mut RecvBatch,
+) -> std::io::Result<usize> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        socket
+            .async_io(Interest::READABLE, || try_recv_batch(socket, batch))
+            .await
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    {
+        let datagram: ReceivedDatagram =
+            recv_datagram_once(socket, batch.bufs[0].buffer(), false).await?;
+        batch.datagrams.clear();
+        batch.datagrams.push((0, datagram));
+        Ok(1)
+    }
+}
+
+/// Performs a single non-blocking `sendmmsg` for the queued replies.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to send from.
+/// - `&[QueuedReply]` - The replies to send.
+///
+/// # Returns
+///
+/// - `std::io::Result<usize>` - The number of replies sent.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn try_send_batch(socket: &UdpSocket, replies: &[QueuedReply]) -> std::io::Result<usize> {
+    let targets: Vec<SockAddr> = replies
+        .iter()
+        .map(|reply: &QueuedReply| SockAddr::from(reply.target))
+        .collect();
+    let mut controls: Vec<ControlBuffer> =
+        replies.iter().map(|_| ControlBuffer::default()).collect();
+    let control_lens: Vec<usize> = replies
+        .iter()
+        .zip(controls.iter_mut())
+        .map(|(reply, control): (&QueuedReply, &mut ControlBuffer)| {
+            let source_ip: Option<IpAddr> =
+                reply.source_ip.filter(|ip: &IpAddr| !ip.is_unspecified());
+            encode_control(control, source_ip, None)
+        })
+        .collect();
+    let mut iovecs: Vec<libc::iovec> = replies
+        .iter()
+        .map(|reply: &QueuedReply| libc::iovec {
+            iov_base: reply.data.as_ptr() as *mut libc::c_void,
+            iov_len: reply.data.len(),
+        })
+        .collect();
+    let mut msgs: Vec<libc::mmsghdr> = (0..replies.len())
+        .map(|index: usize| {
+            // SAFETY: an all-zero `mmsghdr` is a valid empty header.
+            let mut msg: libc::mmsghdr = unsafe { zeroed() };
+            msg.msg_hdr.msg_name = targets[index].as_ptr() as *mut libc::c_void;
+            msg.msg_hdr.msg_namelen = targets[index].len();
+            msg.msg_hdr.msg_iov = &mut iovecs[index];
+            msg.msg_hdr.msg_iovlen = 1;
+            if control_lens[index] > 0 {
+                msg.msg_hdr.msg_control = controls[index].as_mut_ptr() as *mut libc::c_void;
+                msg.msg_hdr.msg_controllen = control_lens[index] as _;
+            }
+            msg
+        })
+        .collect();
+    // SAFETY: every header points at live buffers owned by `replies`, `targets`,
+    // `controls` and `iovecs`, which outlive the call; the payloads are only read.
+    let sent: libc::c_int =
+        unsafe { libc::sendmmsg(socket.as_raw_fd(), msgs.as_mut_ptr(), msgs.len() as _, 0) };
+    if sent < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(sent as usize)
+}
+
+/// Sends every queued reply and reports each outcome to its waiter.
+///
+/// A reply that fails is reported individually and the rest of the batch is retried.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to send from.
+/// - `&mut Vec<QueuedReply>` - The replies to send; emptied on return.
+async fn send_batch(socket: &UdpSocket, batch: &mut Vec<QueuedReply>) {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    while !batch.is_empty() {
+        let result: std::io::Result<usize> = socket
+            .async_io(Interest::WRITABLE, || try_send_batch(socket, batch))
+            .await;
+        match result {
+            Ok(sent) if sent > 0 => {
+                for reply in batch.drain(..sent.min(batch.len())) {
+                    let len: usize = reply.data.len();
+                    let _: Result<(), std::io::Result<usize>> = reply.done.send(Ok(len));
+                }
+            }
+            Ok(_) => {
+                let reply: QueuedReply = batch.remove(0);
+                let _: Result<(), std::io::Result<usize>> = reply
+                    .done
+                    .send(Err(std::io::Error::from(ErrorKind::WriteZero)));
+            }
+            Err(error) => {
+                let reply: QueuedReply = batch.remove(0);
+                let _: Result<(), std::io::Result<usize>> = reply.done.send(Err(error));
+            }
+        }
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    for reply in batch.drain(..) {
+        let result: std::io::Result<usize> =
+            send_datagram(socket, &reply.data, reply.target, reply.source_ip).await;
+        let _: Result<(), std::io::Result<usize>> = reply.done.send(result);
+    }
+}
+
+/// Drains the reply queue of a `ReplyBatcher`, sending up to one batch per call.
+///
+/// # Arguments
+///
+/// - `ArcUdpSocket` - The socket to send from.
+/// - `mpsc::Receiver<QueuedReply>` - The reply queue.
+/// - `usize` - The maximum number of replies per batch.
+pub(crate) async fn reply_loop(
+    socket: ArcUdpSocket,
+    mut receiver: mpsc::Receiver<QueuedReply>,
+    batch_size: usize,
+) {
+    let mut batch: Vec<QueuedReply> = Vec::with_capacity(batch_size);
+    while receiver.recv_many(&mut batch, batch_size).await > 0 {
+        send_batch(&socket, &mut batch).await;
+    }
+}
@@ -0,0 +1,105 @@
+use super::*;
+
+/// Implementation of methods for `ReplyBatcher`.
+impl ReplyBatcher {
+    /// Creates a batcher and spawns its background send task.
+    ///
+    /// The task exits once every clone of the batcher has been dropped.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcUdpSocket` - The socket replies are sent from.
+    /// - `usize` - The maximum number of replies per batch.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The batcher.
+    pub(crate) fn new(socket: ArcUdpSocket, batch_size: usize) -> Self {
+        let batch_size: usize = batch_size.max(1);
+        let (sender, receiver) = mpsc::channel(batch_size.saturating_mul(4));
+        tokio::spawn(reply_loop(socket, receiver, batch_size));
+        Self { sender }
+    }
+
+    /// Queues a reply and waits until it has been sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseData` - The datagram payload.
+    /// - `SocketAddr` - The destination address.
+    /// - `Option<IpAddr>` - The source address to send from.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<usize>` - The number of bytes sent.
+    pub(crate) async fn send(
+        &self,
+        data: ResponseData,
+        target: SocketAddr,
+        source_ip: Option<IpAddr>,
+    ) -> std::io::Result<usize> {
+        let (done, result) = oneshot::channel();
+        let reply: QueuedReply = QueuedReply {
+            data,
+            target,
+            source_ip,
+            done,
+        };
+        let closed = || std::io::Error::new(ErrorKind::BrokenPipe, "reply batcher closed");
+        self.sender.send(reply).await.map_err(|_| closed())?;
+        result.await.map_err(|_| closed())?
+    }
+}
+
+/// Implementation of methods for `RecvBatch`.
+impl RecvBatch {
+    /// Creates a set of receive buffers.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of datagrams per batch.
+    /// - `usize` - The size of each receive buffer.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The receive buffers.
+    pub(crate) fn new(batch_size: usize, buffer_size: usize) -> Self {
+        let batch_size: usize = batch_size.max(1);
+        Self {
+            bufs: (0..batch_size)
+                .map(|_| BufferPool::new(buffer_size))
+                .collect(),
+            datagrams: Vec::with_capacity(batch_size),
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            // SAFETY: an all-zero `sockaddr_storage` is valid.
+            addrs: vec![unsafe { zeroed() }; batch_size],
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            controls: (0..batch_size).map(|_| ControlBuffer::default()).collect(),
+        }
+    }
+
+    /// Gets the number of datagrams received by the last call.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The datagram count.
+    pub(crate) fn len(&self) -> usize {
+        self.datagrams.len()
+    }
+
+    /// Takes a received datagram and its payload out of the batch.
+    ///
+    /// Each datagram must be taken at most once per receive call.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The index of the datagram.
+    ///
+    /// # Returns
+    ///
+    /// - `(ReceivedDatagram, Request)` - The datagram metadata and payload.
+    pub(crate) fn take(&mut self, index: usize) -> (ReceivedDatagram, Request) {
+        let (slot, datagram): (usize, ReceivedDatagram) = self.datagrams[index];
+        (datagram, self.bufs[slot].take(datagram.len))
+    }
+}
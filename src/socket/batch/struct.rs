@@ -0,0 +1,37 @@
+use super::*;
+
+/// A reply waiting in a `ReplyBatcher` queue.
+pub(crate) struct QueuedReply {
+    /// The datagram payload.
+    pub(crate) data: ResponseData,
+    /// The destination address.
+    pub(crate) target: SocketAddr,
+    /// The source address to send from, if known.
+    pub(crate) source_ip: Option<IpAddr>,
+    /// Receives the outcome of the send.
+    pub(crate) done: oneshot::Sender<std::io::Result<usize>>,
+}
+
+/// Coalesces outgoing replies of one socket and sends them in batches.
+///
+/// On Linux each batch is a single `sendmmsg` call; elsewhere the replies are
+/// sent one after another by the same background task.
+#[derive(Clone, Debug)]
+pub(crate) struct ReplyBatcher {
+    /// The queue feeding the background send task.
+    pub(super) sender: mpsc::Sender<QueuedReply>,
+}
+
+/// A set of receive buffers filled by one batched receive call.
+pub(crate) struct RecvBatch {
+    /// The receive buffer pools, one per datagram.
+    pub(super) bufs: Vec<BufferPool>,
+    /// Metadata of the datagrams received by the last call, with the index of their buffer.
+    pub(super) datagrams: Vec<(usize, ReceivedDatagram)>,
+    /// The sender address storage, one per datagram.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(super) addrs: Vec<libc::sockaddr_storage>,
+    /// The ancillary data buffers, one per datagram.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(super) controls: Vec<ControlBuffer>,
+}
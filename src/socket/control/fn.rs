@@ -0,0 +1,223 @@
+use super::*;
+
+/// Sets an integer socket option that has no `socket2` wrapper.
+///
+/// # Arguments
+///
+/// - `&Socket` - The socket to configure.
+/// - `libc::c_int` - The option level.
+/// - `libc::c_int` - The option name.
+/// - `libc::c_int` - The option value.
+///
+/// # Returns
+///
+/// - `std::io::Result<()>` - Result of the `setsockopt` call.
+pub(crate) fn set_int_option(
+    socket: &Socket,
+    level: libc::c_int,
+    name: libc::c_int,
+    value: libc::c_int,
+) -> std::io::Result<()> {
+    // SAFETY: the pointer and length describe a valid `c_int` on the stack.
+    let result: libc::c_int = unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            level,
+            name,
+            &value as *const libc::c_int as *const libc::c_void,
+            size_of::<libc::c_int>() as libc::socklen_t,
+        )
+    };
+    if result != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Enables delivery of the destination address as ancillary data.
+///
+/// IPv4 sockets get `IP_PKTINFO`; IPv6 sockets get `IPV6_RECVPKTINFO` plus
+/// `IP_PKTINFO` for IPv4-mapped traffic on dual-stack sockets.
+///
+/// # Arguments
+///
+/// - `&Socket` - The unbound socket.
+/// - `SocketAddr` - The address the socket will be bound to.
+///
+/// # Returns
+///
+/// - `std::io::Result<()>` - Result of enabling the options.
+pub(crate) fn enable_packet_info(socket: &Socket, addr: SocketAddr) -> std::io::Result<()> {
+    match addr {
+        SocketAddr::V4(_) => set_int_option(socket, libc::IPPROTO_IP, libc::IP_PKTINFO, 1),
+        SocketAddr::V6(_) => {
+            set_int_option(socket, libc::IPPROTO_IPV6, libc::IPV6_RECVPKTINFO, 1)?;
+            if !socket.only_v6().unwrap_or(true) {
+                set_int_option(socket, libc::IPPROTO_IP, libc::IP_PKTINFO, 1)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Enables `UDP_GRO` so the kernel may coalesce consecutive datagrams from one peer.
+///
+/// # Arguments
+///
+/// - `&Socket` - The unbound socket.
+///
+/// # Returns
+///
+/// - `std::io::Result<()>` - Result of enabling the option.
+pub(crate) fn enable_gro(socket: &Socket) -> std::io::Result<()> {
+    set_int_option(socket, libc::SOL_UDP, libc::UDP_GRO, 1)
+}
+
+/// Builds a `msghdr` that points at a received control buffer for `CMSG_*` iteration.
+///
+/// # Arguments
+///
+/// - `*mut u8` - Pointer to the start of the control buffer.
+/// - `usize` - The number of valid control bytes.
+///
+/// # Returns
+///
+/// - `libc::msghdr` - A header describing only the control data.
+fn control_msghdr(control: *mut u8, control_len: usize) -> libc::msghdr {
+    // SAFETY: an all-zero `msghdr` is a valid empty header.
+    let mut msghdr: libc::msghdr = unsafe { zeroed() };
+    msghdr.msg_control = control as *mut libc::c_void;
+    msghdr.msg_controllen = control_len as _;
+    msghdr
+}
+
+/// Extracts the destination IP address from `IP_PKTINFO`/`IPV6_PKTINFO` ancillary data.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The control buffer.
+/// - `usize` - The number of control bytes filled by the kernel.
+///
+/// # Returns
+///
+/// - `Option<IpAddr>` - The local address the datagram was sent to, if reported.
+pub(crate) fn parse_local_ip(control: &[u8], control_len: usize) -> Option<IpAddr> {
+    let msghdr: libc::msghdr =
+        control_msghdr(control.as_ptr() as *mut u8, control_len.min(control.len()));
+    // SAFETY: `msghdr` describes valid bytes of an aligned control buffer that are
+    // only read, and each header is bounds-checked by the `CMSG_*` helpers.
+    unsafe {
+        let mut cmsg: *mut libc::cmsghdr = libc::CMSG_FIRSTHDR(&msghdr);
+        while !cmsg.is_null() {
+            let header: libc::cmsghdr = read_unaligned(cmsg);
+            if header.cmsg_level == libc::IPPROTO_IP && header.cmsg_type == libc::IP_PKTINFO {
+                let info: libc::in_pktinfo =
+                    read_unaligned(libc::CMSG_DATA(cmsg) as *const libc::in_pktinfo);
+                return Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(
+                    info.ipi_addr.s_addr,
+                ))));
+            }
+            if header.cmsg_level == libc::IPPROTO_IPV6 && header.cmsg_type == libc::IPV6_PKTINFO {
+                let info: libc::in6_pktinfo =
+                    read_unaligned(libc::CMSG_DATA(cmsg) as *const libc::in6_pktinfo);
+                return Some(IpAddr::V6(Ipv6Addr::from(info.ipi6_addr.s6_addr)));
+            }
+            cmsg = libc::CMSG_NXTHDR(&msghdr, cmsg);
+        }
+    }
+    None
+}
+
+/// Extracts the GRO segment size from `UDP_GRO` ancillary data.
+///
+/// # Arguments
+///
+/// - `&[u8]` - The control buffer.
+/// - `usize` - The number of control bytes filled by the kernel.
+///
+/// # Returns
+///
+/// - `Option<usize>` - The size of each coalesced segment, if the kernel coalesced datagrams.
+pub(crate) fn parse_segment_size(control: &[u8], control_len: usize) -> Option<usize> {
+    let msghdr: libc::msghdr =
+        control_msghdr(control.as_ptr() as *mut u8, control_len.min(control.len()));
+    // SAFETY: `msghdr` describes valid bytes of an aligned control buffer that are
+    // only read, and each header is bounds-checked by the `CMSG_*` helpers.
+    unsafe {
+        let mut cmsg: *mut libc::cmsghdr = libc::CMSG_FIRSTHDR(&msghdr);
+        while !cmsg.is_null() {
+            let header: libc::cmsghdr = read_unaligned(cmsg);
+            if header.cmsg_level == libc::SOL_UDP && header.cmsg_type == libc::UDP_GRO {
+                let size: libc::c_int = read_unaligned(libc::CMSG_DATA(cmsg) as *const libc::c_int);
+                return usize::try_from(size).ok().filter(|size: &usize| *size > 0);
+            }
+            cmsg = libc::CMSG_NXTHDR(&msghdr, cmsg);
+        }
+    }
+    None
+}
+
+/// Writes source address selection and GSO segmentation messages into the control buffer.
+///
+/// # Arguments
+///
+/// - `&mut ControlBuffer` - The buffer to write into.
+/// - `Option<IpAddr>` - The source address to send from.
+/// - `Option<u16>` - The `UDP_SEGMENT` size to split the payload into.
+///
+/// # Returns
+///
+/// - `usize` - The number of control bytes written.
+pub(crate) fn encode_control(
+    control: &mut ControlBuffer,
+    source_ip: Option<IpAddr>,
+    segment_size: Option<u16>,
+) -> usize {
+    let pktinfo_len: Option<usize> = source_ip.map(|source_ip: IpAddr| match source_ip {
+        IpAddr::V4(_) => size_of::<libc::in_pktinfo>(),
+        IpAddr::V6(_) => size_of::<libc::in6_pktinfo>(),
+    });
+    let segment_len: Option<usize> = segment_size.map(|_| size_of::<u16>());
+    // SAFETY: `CMSG_SPACE` is a pure size computation.
+    let space: usize = [pktinfo_len, segment_len]
+        .into_iter()
+        .flatten()
+        .map(|data_len: usize| unsafe { libc::CMSG_SPACE(data_len as libc::c_uint) } as usize)
+        .sum();
+    if space == 0 {
+        return 0;
+    }
+    let msghdr: libc::msghdr = control_msghdr(control.as_mut_ptr(), space);
+    // SAFETY: the control buffer is aligned and larger than `space`, so every
+    // header written below and its payload are in bounds.
+    unsafe {
+        let mut cmsg: *mut libc::cmsghdr = libc::CMSG_FIRSTHDR(&msghdr);
+        if let (Some(source_ip), Some(data_len)) = (source_ip, pktinfo_len) {
+            (*cmsg).cmsg_len = libc::CMSG_LEN(data_len as libc::c_uint) as _;
+            match source_ip {
+                IpAddr::V4(ip) => {
+                    (*cmsg).cmsg_level = libc::IPPROTO_IP;
+                    (*cmsg).cmsg_type = libc::IP_PKTINFO;
+                    let mut info: libc::in_pktinfo = zeroed();
+                    info.ipi_spec_dst.s_addr = u32::from(ip).to_be();
+                    write_unaligned(libc::CMSG_DATA(cmsg) as *mut libc::in_pktinfo, info);
+                }
+                IpAddr::V6(ip) => {
+                    (*cmsg).cmsg_level = libc::IPPROTO_IPV6;
+                    (*cmsg).cmsg_type = libc::IPV6_PKTINFO;
+                    let mut info: libc::in6_pktinfo = zeroed();
+                    info.ipi6_addr.s6_addr = ip.octets();
+                    write_unaligned(libc::CMSG_DATA(cmsg) as *mut libc::in6_pktinfo, info);
+                }
+            }
+            cmsg = libc::CMSG_NXTHDR(&msghdr, cmsg);
+        }
+        if let (Some(segment_size), Some(data_len)) = (segment_size, segment_len) {
+            (*cmsg).cmsg_len = libc::CMSG_LEN(data_len as libc::c_uint) as _;
+            (*cmsg).cmsg_level = libc::SOL_UDP;
+            (*cmsg).cmsg_type = libc::UDP_SEGMENT;
+            write_unaligned(libc::CMSG_DATA(cmsg) as *mut u16, segment_size);
+        }
+    }
+    space
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+/// Size of the ancillary data buffer used for `recvmsg` and `sendmsg`.
+pub(crate) const CONTROL_BUFFER_SIZE: usize = 128;
+
+/// Maximum number of segments the kernel accepts in one `UDP_SEGMENT` send.
+pub(crate) const MAX_GSO_SEGMENTS: usize = 64;
+
+/// Maximum UDP payload of one `UDP_SEGMENT` send.
+pub(crate) const MAX_GSO_PAYLOAD: usize = 65507;
+
+/// Provides a default implementation for ControlBuffer.
+impl Default for ControlBuffer {
+    /// Creates a zeroed ControlBuffer.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new zeroed buffer.
+    #[inline(always)]
+    fn default() -> Self {
+        Self([0u8; CONTROL_BUFFER_SIZE])
+    }
+}
+
+/// Implementation of ControlBuffer methods.
+impl ControlBuffer {
+    /// Gets the buffer contents.
+    ///
+    /// # Returns
+    ///
+    /// - `&[u8]` - The raw ancillary data bytes.
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Gets a raw pointer to the buffer for building `msghdr` values by hand.
+    ///
+    /// # Returns
+    ///
+    /// - `*mut u8` - Pointer to the start of the buffer.
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.0.as_mut_ptr()
+    }
+
+    /// Gets the buffer as uninitialized memory for `recvmsg`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut [MaybeUninit<u8>]` - The buffer viewed as `MaybeUninit<u8>`.
+    pub(crate) fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        // SAFETY: initialized bytes are valid `MaybeUninit<u8>` values and the
+        // kernel only ever writes initialized bytes into the buffer.
+        unsafe { &mut *(&mut self.0[..] as *mut [u8] as *mut [MaybeUninit<u8>]) }
+    }
+}
@@ -0,0 +1,7 @@
+mod r#fn;
+mod r#impl;
+mod r#struct;
+
+pub(crate) use {r#fn::*, r#impl::*, r#struct::*};
+
+use super::*;
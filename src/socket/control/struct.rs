@@ -0,0 +1,5 @@
+use super::*;
+
+/// Ancillary data buffer aligned for `cmsghdr` access.
+#[repr(C, align(8))]
+pub(crate) struct ControlBuffer(pub(super) [u8; CONTROL_BUFFER_SIZE]);
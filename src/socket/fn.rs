@@ -0,0 +1,466 @@
+use super::*;
+
+/// Applies the configured socket options to an unbound socket.
+///
+/// `TCP_NODELAY` has no meaning for UDP and is ignored.
+///
+/// # Arguments
+///
+/// - `&Socket` - The unbound socket.
+/// - `SocketAddr` - The address the socket will be bound to.
+/// - `&ServerConfigData` - The server configuration.
+///
+/// # Returns
+///
+/// - `std::io::Result<()>` - Result of applying the options.
+fn apply_socket_options(
+    socket: &Socket,
+    addr: SocketAddr,
+    config: &ServerConfigData,
+) -> std::io::Result<()> {
+    if let Some(reuse_address) = config.get_reuse_address() {
+        socket.set_reuse_address(reuse_address)?;
+    }
+    if let Some(reuse_port) = config.get_reuse_port() {
+        #[cfg(unix)]
+        socket.set_reuse_port(reuse_port)?;
+        #[cfg(not(unix))]
+        if reuse_port {
+            return Err(std::io::Error::new(
+                ErrorKind::Unsupported,
+                "SO_REUSEPORT is not supported on this platform",
+            ));
+        }
+    }
+    if let Some(size) = config.get_recv_buffer_size() {
+        socket.set_recv_buffer_size(size)?;
+    }
+    if let Some(size) = config.get_send_buffer_size() {
+        socket.set_send_buffer_size(size)?;
+    }
+    if let Some(broadcast) = config.get_broadcast() {
+        socket.set_broadcast(broadcast)?;
+    }
+    match addr {
+        SocketAddr::V4(_) => {
+            if let Some(ttl) = config.get_ttl() {
+                socket.set_ttl_v4(ttl)?;
+            }
+            if let Some(tos) = config.get_tos() {
+                socket.set_tos_v4(tos)?;
+            }
+        }
+        SocketAddr::V6(_) => {
+            if let Some(only_v6) = config.get_only_v6() {
+                socket.set_only_v6(only_v6)?;
+            }
+            if let Some(ttl) = config.get_ttl() {
+                socket.set_unicast_hops_v6(ttl)?;
+            }
+            if let Some(tos) = config.get_tos() {
+                #[cfg(any(
+                    target_os = "android",
+                    target_os = "freebsd",
+                    target_os = "linux",
+                    target_os = "macos",
+                ))]
+                socket.set_tclass_v6(tos)?;
+                #[cfg(not(any(
+                    target_os = "android",
+                    target_os = "freebsd",
+                    target_os = "linux",
+                    target_os = "macos",
+                )))]
+                return Err(std::io::Error::new(
+                    ErrorKind::Unsupported,
+                    format!("IPV6_TCLASS {tos} is not supported on this platform"),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Creates a non-blocking UDP socket with the configured options and binds it.
+///
+/// # Arguments
+///
+/// - `SocketAddr` - The address to bind to.
+/// - `&ServerConfigData` - The server configuration.
+///
+/// # Returns
+///
+/// - `std::io::Result<UdpSocket>` - The bound socket.
+fn try_bind_udp_socket(addr: SocketAddr, config: &ServerConfigData) -> std::io::Result<UdpSocket> {
+    let socket: Socket = Socket::new(Domain::for_address(addr), Type::DGRAM, Some(Protocol::UDP))?;
+    apply_socket_options(&socket, addr, config)?;
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    if addr.ip().is_unspecified() {
+        enable_packet_info(&socket, addr)?;
+    }
+    if config.get_gro() {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        enable_gro(&socket)?;
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        return Err(std::io::Error::new(
+            ErrorKind::Unsupported,
+            "UDP_GRO is not supported on this platform",
+        ));
+    }
+    socket.set_nonblocking(true)?;
+    socket.bind(&addr.into())?;
+    UdpSocket::from_std(socket.into())
+}
+
+/// Duplicates a UDP socket handle, so two owners can use the same socket.
+///
+/// The new handle is registered with the current Tokio runtime on its own.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to duplicate.
+///
+/// # Returns
+///
+/// - `std::io::Result<UdpSocket>` - A new handle to the same socket, or an error
+///   if the handle cannot be duplicated or no Tokio runtime is running.
+pub(crate) fn duplicate_udp_socket(socket: &UdpSocket) -> std::io::Result<UdpSocket> {
+    Handle::try_current().map_err(std::io::Error::other)?;
+    let duplicate: Socket = SockRef::from(socket).try_clone()?;
+    duplicate.set_nonblocking(true)?;
+    UdpSocket::from_std(duplicate.into())
+}
+
+/// Binds a UDP socket to the given address, honoring the configured socket options.
+///
+/// # Arguments
+///
+/// - `SocketAddr` - The address to bind to.
+/// - `&ServerConfigData` - The server configuration.
+///
+/// # Returns
+///
+/// - `Result<UdpSocket, ServerError>` - The bound socket, or `ServerError::UdpBind` on failure.
+pub(crate) fn bind_udp_socket(
+    addr: SocketAddr,
+    config: &ServerConfigData,
+) -> Result<UdpSocket, ServerError> {
+    try_bind_udp_socket(addr, config)
+        .map_err(|error: std::io::Error| ServerError::UdpBind(format!("{addr}: {error}")))
+}
+
+/// Performs a single non-blocking `recvmsg` on the socket.
+///
+/// On Linux and Android `MSG_TRUNC` is passed so the returned datagram length is
+/// the full size on the wire even when the buffer was too small, and the
+/// destination address is read from `IP_PKTINFO`/`IPV6_PKTINFO` ancillary data.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to receive from.
+/// - `&mut [MaybeUninit<u8>]` - The receive buffer.
+/// - `bool` - Whether to leave the datagram in the socket queue (`MSG_PEEK`).
+///
+/// # Returns
+///
+/// - `std::io::Result<ReceivedDatagram>` - The received datagram metadata.
+#[cfg(unix)]
+fn try_recv_datagram(
+    socket: &UdpSocket,
+    buf: &mut [MaybeUninit<u8>],
+    peek: bool,
+) -> std::io::Result<ReceivedDatagram> {
+    let mut flags: libc::c_int = 0;
+    if peek {
+        flags |= libc::MSG_PEEK;
+    }
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        flags |= libc::MSG_TRUNC;
+    }
+    let buf_len: usize = buf.len();
+    let storage: SockAddrStorage = SockAddrStorage::zeroed();
+    let storage_len: libc::socklen_t = storage.size_of();
+    // SAFETY: the storage is zeroed and its full size is reported as the length.
+    let mut addr: SockAddr = unsafe { SockAddr::new(storage, storage_len) };
+    let mut bufs: [MaybeUninitSlice<'_>; 1] = [MaybeUninitSlice::new(buf)];
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let mut control: ControlBuffer = ControlBuffer::default();
+    let msg: MsgHdrMut<'_, '_, '_> = MsgHdrMut::new()
+        .with_addr(&mut addr)
+        .with_buffers(&mut bufs);
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let msg: MsgHdrMut<'_, '_, '_> = msg.with_control(control.as_uninit_mut());
+    let mut msg: MsgHdrMut<'_, '_, '_> = msg;
+    let datagram_len: usize = SockRef::from(socket).recvmsg(&mut msg, flags)?;
+    let truncated: bool = msg.flags().is_truncated() || datagram_len > buf_len;
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let control_len: usize = msg.control_len();
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let local_ip: Option<IpAddr> = parse_local_ip(control.as_bytes(), control_len);
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let segment_size: Option<usize> = parse_segment_size(control.as_bytes(), control_len);
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    let local_ip: Option<IpAddr> = None;
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    let segment_size: Option<usize> = None;
+    let client_addr: SocketAddr = addr.as_socket().ok_or_else(|| {
+        std::io::Error::new(ErrorKind::InvalidData, "unsupported sender address family")
+    })?;
+    Ok(ReceivedDatagram {
+        len: datagram_len.min(buf_len),
+        datagram_len,
+        client_addr,
+        local_ip,
+        truncated,
+        segment_size,
+    })
+}
+
+/// Receives or peeks a single datagram, waiting for the socket to become readable.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to receive from.
+/// - `&mut [MaybeUninit<u8>]` - The receive buffer.
+/// - `bool` - Whether to leave the datagram in the socket queue.
+///
+/// # Returns
+///
+/// - `std::io::Result<ReceivedDatagram>` - The received datagram metadata.
+pub(crate) async fn recv_datagram_once(
+    socket: &UdpSocket,
+    buf: &mut [MaybeUninit<u8>],
+    peek: bool,
+) -> std::io::Result<ReceivedDatagram> {
+    #[cfg(unix)]
+    {
+        socket
+            .async_io(Interest::READABLE, || try_recv_datagram(socket, buf, peek))
+            .await
+    }
+    #[cfg(not(unix))]
+    {
+        let (len, client_addr) = if peek {
+            buf.fill(MaybeUninit::new(0));
+            // SAFETY: every byte of the buffer was initialized just above.
+            let buf: &mut [u8] = unsafe { &mut *(buf as *mut [MaybeUninit<u8>] as *mut [u8]) };
+            socket.peek_from(buf).await?
+        } else {
+            let mut buf: &mut [MaybeUninit<u8>] = buf;
+            socket.recv_buf_from(&mut buf).await?
+        };
+        Ok(ReceivedDatagram {
+            len,
+            datagram_len: len,
+            client_addr,
+            local_ip: None,
+            truncated: false,
+            segment_size: None,
+        })
+    }
+}
+
+/// Receives a single datagram into the buffer pool, applying the configured truncation policy.
+///
+/// With `TruncationPolicy::Grow` the datagram is peeked first and the pool's receive
+/// size is enlarged until the datagram fits or `MAX_DATAGRAM_SIZE` is reached. The
+/// payload is left in the pool to be split off with `BufferPool::take`.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to receive from.
+/// - `&mut BufferPool` - The buffer pool, whose receive size may be grown.
+/// - `TruncationPolicy` - The truncation policy.
+///
+/// # Returns
+///
+/// - `std::io::Result<ReceivedDatagram>` - The received datagram metadata.
+pub(crate) async fn recv_datagram(
+    socket: &UdpSocket,
+    pool: &mut BufferPool,
+    policy: TruncationPolicy,
+) -> std::io::Result<ReceivedDatagram> {
+    if policy == TruncationPolicy::Grow {
+        loop {
+            let buffer_size: usize = pool.get_buffer_size();
+            let peeked: ReceivedDatagram = recv_datagram_once(socket, pool.buffer(), true).await?;
+            if !peeked.truncated || buffer_size >= MAX_DATAGRAM_SIZE {
+                break;
+            }
+            let new_len: usize = if peeked.datagram_len > buffer_size {
+                peeked.datagram_len
+            } else {
+                buffer_size.saturating_mul(2)
+            };
+            pool.set_buffer_size(new_len.min(MAX_DATAGRAM_SIZE));
+        }
+    }
+    recv_datagram_once(socket, pool.buffer(), false).await
+}
+
+/// Sends a datagram with ancillary data built from the source address and GSO segment size.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to send from.
+/// - `&[u8]` - The datagram payload.
+/// - `SocketAddr` - The destination address.
+/// - `Option<IpAddr>` - The source address to send from.
+/// - `Option<u16>` - The `UDP_SEGMENT` size to split the payload into.
+///
+/// # Returns
+///
+/// - `std::io::Result<usize>` - The number of bytes sent.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+async fn send_with_control(
+    socket: &UdpSocket,
+    data: &[u8],
+    target: SocketAddr,
+    source_ip: Option<IpAddr>,
+    segment_size: Option<u16>,
+) -> std::io::Result<usize> {
+    let mut control: ControlBuffer = ControlBuffer::default();
+    let control_len: usize = encode_control(&mut control, source_ip, segment_size);
+    let target_addr: SockAddr = SockAddr::from(target);
+    socket
+        .async_io(Interest::WRITABLE, || {
+            let bufs: [IoSlice<'_>; 1] = [IoSlice::new(data)];
+            let msg: MsgHdr<'_, '_, '_> = MsgHdr::new()
+                .with_addr(&target_addr)
+                .with_buffers(&bufs)
+                .with_control(&control.as_bytes()[..control_len]);
+            SockRef::from(socket).sendmsg(&msg, 0)
+        })
+        .await
+}
+
+/// Sends a datagram, selecting the source address through `IP_PKTINFO` when possible.
+///
+/// On platforms without packet info support, or when no specific source address
+/// is given, this is a plain `send_to`.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to send from.
+/// - `&[u8]` - The datagram payload.
+/// - `SocketAddr` - The destination address.
+/// - `Option<IpAddr>` - The source address to send from.
+///
+/// # Returns
+///
+/// - `std::io::Result<usize>` - The number of bytes sent.
+pub(crate) async fn send_datagram(
+    socket: &UdpSocket,
+    data: &[u8],
+    target: SocketAddr,
+    source_ip: Option<IpAddr>,
+) -> std::io::Result<usize> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    if let Some(source_ip) = source_ip.filter(|ip: &IpAddr| !ip.is_unspecified()) {
+        return send_with_control(socket, data, target, Some(source_ip), None).await;
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    let _: Option<IpAddr> = source_ip;
+    socket.send_to(data, target).await
+}
+
+/// Sends a payload as one datagram per `segment_size` bytes, without offload.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to send from.
+/// - `&[u8]` - The payload to split.
+/// - `usize` - The size of each datagram; the last one may be shorter.
+/// - `SocketAddr` - The destination address.
+/// - `Option<IpAddr>` - The source address to send from.
+///
+/// # Returns
+///
+/// - `std::io::Result<usize>` - The number of bytes sent.
+async fn send_each_segment(
+    socket: &UdpSocket,
+    data: &[u8],
+    segment_size: usize,
+    target: SocketAddr,
+    source_ip: Option<IpAddr>,
+) -> std::io::Result<usize> {
+    let mut sent: usize = 0;
+    for segment in data.chunks(segment_size) {
+        sent += send_datagram(socket, segment, target, source_ip).await?;
+    }
+    Ok(sent)
+}
+
+/// Checks whether a send failed because the kernel or device rejected `UDP_SEGMENT`.
+///
+/// # Arguments
+///
+/// - `&std::io::Error` - The send error.
+///
+/// # Returns
+///
+/// - `bool` - `true` if the payload should be resent without offload.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn is_gso_rejected(error: &std::io::Error) -> bool {
+    matches!(
+        error.raw_os_error(),
+        Some(libc::EIO | libc::EINVAL | libc::ENOPROTOOPT | libc::EOPNOTSUPP)
+    )
+}
+
+/// Sends a payload split into equally sized datagrams, using UDP GSO where available.
+///
+/// On Linux the payload is handed to the kernel in as few `UDP_SEGMENT` sends as
+/// possible. If the kernel or device rejects segmentation, or on other platforms,
+/// each datagram is sent individually.
+///
+/// # Arguments
+///
+/// - `&UdpSocket` - The socket to send from.
+/// - `&[u8]` - The payload to split.
+/// - `usize` - The size of each datagram; the last one may be shorter.
+/// - `SocketAddr` - The destination address.
+/// - `Option<IpAddr>` - The source address to send from.
+///
+/// # Returns
+///
+/// - `std::io::Result<usize>` - The number of bytes sent.
+pub(crate) async fn send_segmented_datagram(
+    socket: &UdpSocket,
+    data: &[u8],
+    segment_size: usize,
+    target: SocketAddr,
+    source_ip: Option<IpAddr>,
+) -> std::io::Result<usize> {
+    if segment_size == 0 {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidInput,
+            "segment size must be greater than zero",
+        ));
+    }
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    if let Ok(gso_size) = u16::try_from(segment_size)
+        && data.len() > segment_size
+    {
+        let source_ip: Option<IpAddr> = source_ip.filter(|ip: &IpAddr| !ip.is_unspecified());
+        let segments_per_send: usize = MAX_GSO_SEGMENTS.min(MAX_GSO_PAYLOAD / segment_size).max(1);
+        let mut sent: usize = 0;
+        for group in data.chunks(segment_size * segments_per_send) {
+            if group.len() <= segment_size {
+                sent += send_datagram(socket, group, target, source_ip).await?;
+                continue;
+            }
+            sent += match send_with_control(socket, group, target, source_ip, Some(gso_size)).await
+            {
+                Ok(len) => len,
+                Err(error) if is_gso_rejected(&error) => {
+                    send_each_segment(socket, group, segment_size, target, source_ip).await?
+                }
+                Err(error) => return Err(error),
+            };
+        }
+        return Ok(sent);
+    }
+    send_each_segment(socket, data, segment_size, target, source_ip).await
+}
@@ -2,17 +2,20 @@ use super::*;
 
 /// Implementation of ArcRwLockUdpSocket methods.
 impl ArcRwLockUdpSocket {
-    /// Creates a new instance from existing `ArcRwLock<UdpSocket>`.
+    /// Creates a new instance from an existing shared socket.
     ///
     /// # Arguments
     ///
-    /// - `ArcRwLock<UdpSocket>` - Existing socket with read-write lock.
+    /// - `ArcUdpSocket` - Existing shared socket.
     ///
     /// # Returns
     ///
     /// - `ArcRwLockUdpSocket` - New wrapper instance.
-    pub fn from(socket: ArcRwLock<UdpSocket>) -> Self {
-        Self { socket }
+    pub fn from_udp_socket(udp_socket: ArcUdpSocket) -> Self {
+        Self {
+            udp_socket,
+            socket: Arc::default(),
+        }
     }
 
     /// Creates a new instance from raw UdpSocket.
@@ -25,26 +28,140 @@ impl ArcRwLockUdpSocket {
     ///
     /// - `ArcRwLockUdpSocket` - New wrapper instance.
     pub fn from_socket(socket: UdpSocket) -> Self {
-        Self {
-            socket: arc_rwlock(socket),
-        }
+        Self::from_udp_socket(Arc::new(socket))
+    }
+
+    /// Gets the shared socket.
+    ///
+    /// # Returns
+    ///
+    /// - `ArcUdpSocket` - The shared socket.
+    pub fn get_udp_socket(&self) -> ArcUdpSocket {
+        self.udp_socket.clone()
+    }
+
+    /// Gets the locked handle, duplicating the shared socket on first use.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<&ArcRwLock<UdpSocket>>` - The locked handle, or the
+    ///   error raised while duplicating the socket.
+    async fn get_lock(&self) -> std::io::Result<&ArcRwLock<UdpSocket>> {
+        self.socket
+            .get_or_try_init(|| async { duplicate_udp_socket(&self.udp_socket).map(arc_rwlock) })
+            .await
     }
 
     /// Acquires a read lock on the socket.
     ///
+    /// The locked socket is a duplicated handle of the shared socket. The lock does
+    /// not exclude the server, which sends and receives through the shared socket
+    /// without locking, so the handle is only safe for sending: receiving through
+    /// it takes datagrams away from the server's receive loop.
+    ///
     /// # Returns
     ///
-    /// - `RwLockReadGuard<UdpSocket>` - Read guard for the socket.
-    pub async fn get_read_lock(&self) -> RwLockReadGuard<'_, UdpSocket> {
-        self.socket.read().await
+    /// - `std::io::Result<RwLockReadGuard<UdpSocket>>` - Read guard for the socket,
+    ///   or the error raised while duplicating the socket.
+    #[deprecated(note = "use `get_udp_socket`, the socket needs no lock")]
+    pub async fn get_read_lock(&self) -> std::io::Result<RwLockReadGuard<'_, UdpSocket>> {
+        Ok(self.get_lock().await?.read().await)
     }
 
     /// Acquires a write lock on the socket.
     ///
+    /// The locked socket is a duplicated handle of the shared socket. The lock does
+    /// not exclude the server, which sends and receives through the shared socket
+    /// without locking, so the handle is only safe for sending: receiving through
+    /// it takes datagrams away from the server's receive loop.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<RwLockWriteGuard<UdpSocket>>` - Write guard for the socket,
+    ///   or the error raised while duplicating the socket.
+    #[deprecated(note = "use `get_udp_socket`, the socket needs no lock")]
+    pub async fn get_write_lock(&self) -> std::io::Result<RwLockWriteGuard<'_, UdpSocket>> {
+        Ok(self.get_lock().await?.write().await)
+    }
+}
+
+/// Wraps an existing locked socket in the compatibility wrapper.
+///
+/// The lock stays shared with the caller and backs the deprecated lock accessors,
+/// while the server works on a duplicated handle of the same socket. The lock
+/// therefore does not exclude the server, and receiving through the locked socket
+/// takes datagrams away from the server's receive loop.
+impl TryFrom<ArcRwLock<UdpSocket>> for ArcRwLockUdpSocket {
+    type Error = std::io::Error;
+
+    /// Duplicates the locked socket into the shared socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLock<UdpSocket>` - Existing socket with read-write lock.
+    ///
+    /// # Returns
+    ///
+    /// - `std::io::Result<ArcRwLockUdpSocket>` - The wrapper, or an error if the
+    ///   socket is write-locked, its handle cannot be duplicated, or no Tokio
+    ///   runtime is running.
+    fn try_from(socket: ArcRwLock<UdpSocket>) -> Result<Self, Self::Error> {
+        let udp_socket: UdpSocket = {
+            let guard: RwLockReadGuard<'_, UdpSocket> =
+                socket.try_read().map_err(std::io::Error::other)?;
+            duplicate_udp_socket(&guard)?
+        };
+        Ok(Self {
+            udp_socket: Arc::new(udp_socket),
+            socket: Arc::new(OnceCell::new_with(Some(socket))),
+        })
+    }
+}
+
+/// Converts a compatibility wrapper into the shared socket.
+impl From<ArcRwLockUdpSocket> for ArcUdpSocket {
+    /// Unwraps the shared socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcRwLockUdpSocket` - The wrapper.
+    ///
+    /// # Returns
+    ///
+    /// - `ArcUdpSocket` - The shared socket.
+    fn from(socket: ArcRwLockUdpSocket) -> Self {
+        socket.udp_socket
+    }
+}
+
+/// Converts a borrowed compatibility wrapper into the shared socket.
+impl From<&ArcRwLockUdpSocket> for ArcUdpSocket {
+    /// Clones the shared socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ArcRwLockUdpSocket` - The wrapper.
+    ///
+    /// # Returns
+    ///
+    /// - `ArcUdpSocket` - The shared socket.
+    fn from(socket: &ArcRwLockUdpSocket) -> Self {
+        socket.get_udp_socket()
+    }
+}
+
+/// Wraps a shared socket in the compatibility wrapper.
+impl From<ArcUdpSocket> for ArcRwLockUdpSocket {
+    /// Wraps the shared socket.
+    ///
+    /// # Arguments
+    ///
+    /// - `ArcUdpSocket` - The shared socket.
+    ///
     /// # Returns
     ///
-    /// - `RwLockWriteGuard<UdpSocket>` - Write guard for the socket.
-    pub async fn get_write_lock(&self) -> RwLockWriteGuard<'_, UdpSocket> {
-        self.socket.write().await
+    /// - `ArcRwLockUdpSocket` - The wrapper.
+    fn from(socket: ArcUdpSocket) -> Self {
+        Self::from_udp_socket(socket)
     }
 }
@@ -1,7 +1,16 @@
+mod batch;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod control;
+mod r#fn;
 mod r#impl;
+mod pool;
 mod r#struct;
 mod r#type;
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) use control::*;
+pub(crate) use {batch::*, r#fn::*, pool::*};
+
 pub use {r#struct::*, r#type::*};
 
 use super::*;
@@ -0,0 +1,84 @@
+use super::*;
+
+/// The number of full-size receive buffers that fit in one slab.
+const SLAB_BUFFERS: usize = 2;
+
+/// Implementation of methods for BufferPool.
+impl BufferPool {
+    /// Creates a pool for receives of up to `buffer_size` bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of bytes one receive may write.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The buffer pool.
+    pub(crate) fn new(buffer_size: usize) -> Self {
+        Self {
+            buf: BytesMut::new(),
+            buffer_size: buffer_size.max(1),
+            lent: 0,
+        }
+    }
+
+    /// Gets the number of bytes one receive may write.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The receive size.
+    pub(crate) fn get_buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Sets the number of bytes one receive may write.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The receive size.
+    pub(crate) fn set_buffer_size(&mut self, buffer_size: usize) {
+        self.buffer_size = buffer_size.max(1);
+    }
+
+    /// Gets an uninitialized buffer of the receive size, moving to a fresh slab if needed.
+    ///
+    /// The buffer is the spare capacity of the slab and is never zero-filled, so
+    /// only the bytes a receive actually writes are touched.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut [MaybeUninit<u8>]` - The buffer to receive into.
+    pub(crate) fn buffer(&mut self) -> &mut [MaybeUninit<u8>] {
+        if self.buf.capacity() < self.buffer_size {
+            self.buf
+                .reserve(self.buffer_size.saturating_mul(SLAB_BUFFERS));
+        }
+        self.lent = self.buffer_size;
+        &mut self.buf.spare_capacity_mut()[..self.buffer_size]
+    }
+
+    /// Splits the first `len` received bytes off as a request.
+    ///
+    /// Must only be called after a receive into the last `buffer` reported writing
+    /// `len` bytes. A larger `len` is clamped to the length of that buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of bytes received into the last buffer.
+    ///
+    /// # Returns
+    ///
+    /// - `Request` - The received bytes, sharing the slab without copying.
+    pub(crate) fn take(&mut self, len: usize) -> Request {
+        debug_assert!(self.buf.is_empty());
+        debug_assert!(len <= self.lent);
+        let len: usize = len.min(self.lent);
+        self.lent = 0;
+        // SAFETY: `buffer` handed out the first `lent` bytes of the spare capacity of
+        // the empty slab, so they start at offset 0 and lie within its capacity. The
+        // caller passes the byte count the kernel reported writing into that buffer,
+        // and `len` is clamped to `lent`, so the first `len` bytes are initialized.
+        unsafe { self.buf.set_len(len) };
+        self.buf.split().freeze()
+    }
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+/// A reusable receive buffer that hands out datagrams as shared `Request` slices.
+///
+/// Datagrams are received directly into the uninitialized spare capacity of a slab
+/// and split off without copying. A slab is reclaimed for reuse once every request
+/// cut from it has been dropped; otherwise a new slab is allocated. Any request
+/// still held keeps its whole slab allocated.
+#[derive(Debug)]
+pub(crate) struct BufferPool {
+    /// The unused remainder of the current slab, always empty between receives.
+    pub(super) buf: BytesMut,
+    /// The number of bytes one receive may write.
+    pub(super) buffer_size: usize,
+    /// The length of the buffer last handed out by `buffer`, zero once taken.
+    pub(super) lent: usize,
+}
@@ -1,10 +1,33 @@
 use super::*;
 
-/// Thread-safe wrapper for UDP socket with read-write lock.
+/// Compatibility wrapper around a shared UDP socket.
 ///
-/// Provides synchronized access to UDP socket operations.
+/// The socket is no longer guarded by a lock: `UdpSocket` only needs `&self`
+/// to send and receive, so it is shared as a plain `Arc<UdpSocket>` and read
+/// with `get_udp_socket`. The deprecated lock accessors are kept for existing
+/// code; they lock a duplicated handle of the same socket, which does not
+/// exclude the server and must not be used to receive.
 #[derive(Clone, Debug)]
 pub struct ArcRwLockUdpSocket {
-    /// Underlying UDP socket with read-write lock.
-    pub(super) socket: ArcRwLock<UdpSocket>,
+    /// Underlying shared UDP socket.
+    pub(super) udp_socket: ArcUdpSocket,
+    /// The locked handle behind the deprecated lock accessors, created on first use.
+    pub(super) socket: Arc<OnceCell<ArcRwLock<UdpSocket>>>,
+}
+
+/// Metadata describing a single received datagram.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ReceivedDatagram {
+    /// The number of bytes copied into the receive buffer.
+    pub(crate) len: usize,
+    /// The size of the datagram on the wire, when the platform reports it.
+    pub(crate) datagram_len: usize,
+    /// The address of the sender.
+    pub(crate) client_addr: SocketAddr,
+    /// The local address the datagram was sent to, when reported by the kernel.
+    pub(crate) local_ip: Option<IpAddr>,
+    /// Whether the datagram was larger than the receive buffer.
+    pub(crate) truncated: bool,
+    /// The size of each datagram the kernel coalesced into this one through GRO.
+    pub(crate) segment_size: Option<usize>,
 }
@@ -1,6 +1,12 @@
 use super::*;
 
-/// Optional thread-safe read-write locked UDP socket.
+/// Shared UDP socket.
+pub type ArcUdpSocket = Arc<UdpSocket>;
+
+/// Optional shared UDP socket.
+pub type OptionArcUdpSocket = Option<ArcUdpSocket>;
+
+/// Optional compatibility wrapper around a shared UDP socket.
 pub type OptionArcRwLockUdpSocket = Option<ArcRwLockUdpSocket>;
 
 /// Optional socket address (IP + port).
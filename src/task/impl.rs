@@ -0,0 +1,282 @@
+use super::*;
+
+/// Provides a default implementation for TaskTracker.
+impl Default for TaskTracker {
+    /// Creates an empty, unbounded TaskTracker.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A tracker with no running tasks.
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_IN_FLIGHT, DEFAULT_OVERLOAD_POLICY)
+    }
+}
+
+/// Implementation of methods for TaskTracker.
+impl TaskTracker {
+    /// Creates an empty TaskTracker with a concurrency limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<usize>` - The maximum number of running tasks, or `None` for unbounded.
+    /// - `OverloadPolicy` - The policy applied when the limit is reached.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A tracker with no running tasks.
+    pub(crate) fn new(max_in_flight: Option<usize>, overload_policy: OverloadPolicy) -> Self {
+        Self(Arc::new(TaskTrackerData {
+            handles: Mutex::new(BTreeMap::new()),
+            next_id: AtomicU64::new(0),
+            idle: Notify::new(),
+            released: Notify::new(),
+            max_in_flight,
+            overload_policy,
+            dropped: AtomicU64::new(0),
+        }))
+    }
+
+    /// Locks the handle map, recovering it if a previous holder panicked.
+    ///
+    /// # Returns
+    ///
+    /// - `MutexGuard<BTreeMap<u64, Option<AbortHandle>>>` - The guard for the handle map.
+    fn handles(&self) -> MutexGuard<'_, BTreeMap<u64, Option<AbortHandle>>> {
+        self.0.handles.lock().unwrap_or_else(
+            |error: PoisonError<MutexGuard<'_, BTreeMap<u64, Option<AbortHandle>>>>| {
+                error.into_inner()
+            },
+        )
+    }
+
+    /// Reserves a slot for a new task while the handle map is locked.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BTreeMap<u64, Option<AbortHandle>>` - The locked handle map.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The id of the reserved slot.
+    fn reserve_locked(&self, handles: &mut BTreeMap<u64, Option<AbortHandle>>) -> u64 {
+        let id: u64 = self.0.next_id.fetch_add(1, Ordering::Relaxed);
+        handles.insert(id, None);
+        id
+    }
+
+    /// Spawns a task into a reserved slot and records its abort handle.
+    ///
+    /// Must be called without the handle map locked: while the runtime shuts down,
+    /// `tokio::spawn` drops the future in place, which releases the slot. When the
+    /// slot is gone by the time the task is recorded, the task either finished or
+    /// was cancelled by `abort_all`, and it is aborted.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The id of the reserved slot.
+    /// - `F` - The future to run.
+    fn spawn_reserved<F>(&self, id: u64, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let slot: TaskSlot = TaskSlot {
+            tracker: self.clone(),
+            id,
+        };
+        let handle: JoinHandle<()> = tokio::spawn(async move {
+            let _slot: TaskSlot = slot;
+            future.await;
+        });
+        let recorded: bool = self
+            .handles()
+            .get_mut(&id)
+            .map(|entry: &mut Option<AbortHandle>| *entry = Some(handle.abort_handle()))
+            .is_some();
+        if !recorded {
+            handle.abort();
+        }
+    }
+
+    /// Spawns a task and tracks it until it completes, honoring the concurrency limit.
+    ///
+    /// When the limit is reached the overload policy decides whether the new task is
+    /// dropped, the oldest running task is cancelled, or the caller waits for a free slot.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The future to run.
+    pub(crate) async fn spawn<F>(&self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        loop {
+            let released: Notified<'_> = self.0.released.notified();
+            let reserved: Option<u64> = {
+                let mut handles: MutexGuard<'_, BTreeMap<u64, Option<AbortHandle>>> =
+                    self.handles();
+                let full: bool = self
+                    .0
+                    .max_in_flight
+                    .is_some_and(|max: usize| handles.len() >= max);
+                if !full {
+                    Some(self.reserve_locked(&mut handles))
+                } else {
+                    match self.0.overload_policy {
+                        OverloadPolicy::DropNewest => {
+                            self.0.dropped.fetch_add(1, Ordering::Relaxed);
+                            return;
+                        }
+                        OverloadPolicy::DropOldest => {
+                            if let Some((_, Some(oldest))) = handles.pop_first() {
+                                oldest.abort();
+                            }
+                            self.0.dropped.fetch_add(1, Ordering::Relaxed);
+                            Some(self.reserve_locked(&mut handles))
+                        }
+                        OverloadPolicy::Block => None,
+                    }
+                }
+            };
+            if let Some(id) = reserved {
+                self.spawn_reserved(id, future);
+                return;
+            }
+            released.await;
+        }
+    }
+
+    /// Spawns a task and tracks it only if a slot is free right now.
+    ///
+    /// Unlike `spawn`, this never waits or cancels a running task: when the limit is
+    /// reached the task is discarded and counted as dropped. Used for auxiliary hook
+    /// chains that must not hold up the receive loop or evict request handlers.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The future to run.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the task was spawned.
+    pub(crate) fn try_spawn<F>(&self, future: F) -> bool
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let id: u64 = {
+            let mut handles: MutexGuard<'_, BTreeMap<u64, Option<AbortHandle>>> = self.handles();
+            if self
+                .0
+                .max_in_flight
+                .is_some_and(|max: usize| handles.len() >= max)
+            {
+                self.0.dropped.fetch_add(1, Ordering::Relaxed);
+                return false;
+            }
+            self.reserve_locked(&mut handles)
+        };
+        self.spawn_reserved(id, future);
+        true
+    }
+
+    /// Counts a task that was discarded before `try_spawn` was attempted.
+    pub(crate) fn count_dropped(&self) {
+        self.0.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Removes a finished task and wakes idle waiters if it was the last one.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The id of the finished task.
+    fn finish(&self, id: u64) {
+        let mut handles: MutexGuard<'_, BTreeMap<u64, Option<AbortHandle>>> = self.handles();
+        handles.remove(&id);
+        self.0.released.notify_waiters();
+        if handles.is_empty() {
+            self.0.idle.notify_waiters();
+        }
+    }
+
+    /// Gets the number of tasks that are still running.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of in-flight tasks.
+    pub(crate) fn len(&self) -> usize {
+        self.handles().len()
+    }
+
+    /// Checks whether the concurrency limit is reached.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if a new task would not get a free slot right now.
+    pub(crate) fn is_full(&self) -> bool {
+        self.0
+            .max_in_flight
+            .is_some_and(|max: usize| self.len() >= max)
+    }
+
+    /// Gets the number of tasks dropped or cancelled because of the concurrency limit.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The dropped task count.
+    pub(crate) fn dropped(&self) -> u64 {
+        self.0.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Waits until no tracked task is running.
+    pub(crate) async fn wait_idle(&self) {
+        loop {
+            let notified: Notified<'_> = self.0.idle.notified();
+            if self.len() == 0 {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Aborts every task that is still running.
+    pub(crate) fn abort_all(&self) {
+        let mut handles: MutexGuard<'_, BTreeMap<u64, Option<AbortHandle>>> = self.handles();
+        for handle in std::mem::take(&mut *handles).into_values().flatten() {
+            handle.abort();
+        }
+        self.0.released.notify_waiters();
+        self.0.idle.notify_waiters();
+    }
+}
+
+/// Implementation of Drop for TaskSlot.
+impl Drop for TaskSlot {
+    /// Releases the slot, whether the task completed, panicked or was aborted.
+    fn drop(&mut self) {
+        self.tracker.finish(self.id);
+    }
+}
+
+/// Implementation of Drop for AbortOnDrop.
+impl Drop for AbortOnDrop {
+    /// Aborts the wrapped task.
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Implementation of methods for AbortOnDrop.
+impl AbortOnDrop {
+    /// Wraps a task handle so the task is aborted when the guard is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// - `&JoinHandle<T>` - The handle of the task to guard.
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The guard.
+    pub(crate) fn new<T>(handle: &JoinHandle<T>) -> Self {
+        Self(handle.abort_handle())
+    }
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+/// Shared state behind a `TaskTracker`.
+pub(crate) struct TaskTrackerData {
+    /// Abort handles of the tasks that are still running, keyed by tracker-local id.
+    ///
+    /// Ids increase monotonically, so the first entry is always the oldest task.
+    /// A slot holds `None` while its task is being spawned.
+    pub(super) handles: Mutex<BTreeMap<u64, Option<AbortHandle>>>,
+    /// The id assigned to the next spawned task.
+    pub(super) next_id: AtomicU64,
+    /// Notified whenever the last running task finishes.
+    pub(super) idle: Notify,
+    /// Notified whenever any running task finishes.
+    pub(super) released: Notify,
+    /// The maximum number of tasks allowed to run at once; `None` means unbounded.
+    pub(super) max_in_flight: Option<usize>,
+    /// The policy applied when `max_in_flight` tasks are already running.
+    pub(super) overload_policy: OverloadPolicy,
+    /// The number of tasks dropped or cancelled because of the limit.
+    pub(super) dropped: AtomicU64,
+}
+
+/// Tracks spawned request handler tasks so they can be counted, drained and cancelled.
+#[derive(Clone)]
+pub(crate) struct TaskTracker(pub(super) Arc<TaskTrackerData>);
+
+/// Releases a tracked task's slot when the task's future is dropped.
+///
+/// The slot is moved into the spawned future, so it is released on completion,
+/// on panic and on abort alike.
+pub(crate) struct TaskSlot {
+    /// The tracker that owns the slot.
+    pub(super) tracker: TaskTracker,
+    /// The id of the tracked task.
+    pub(super) id: u64,
+}
+
+/// Aborts the wrapped task when dropped.
+#[derive(Debug)]
+pub(crate) struct AbortOnDrop(pub(super) AbortHandle);
@@ -1,5 +1,37 @@
 use super::*;
 
+async fn reply_truncation(ctx: Context) {
+    let request: Request = ctx.get_request().await;
+    let truncated: bool = ctx.get_truncated().await;
+    let response: String = format!("{truncated}:{}", request.len());
+    let _: Result<(), ResponseError> = ctx.send(response).await;
+}
+
+async fn reply_request_error(ctx: Context) {
+    let error: RequestError = ctx.get_request_error().await;
+    let _: Result<(), ResponseError> = ctx.send(error.to_string()).await;
+}
+
+async fn reply_local_addr(ctx: Context) {
+    let local_addr: SocketAddr = ctx.get_local_addr().await;
+    let _: Result<(), ResponseError> = ctx.send(local_addr.to_string()).await;
+}
+
+async fn reply_limited(ctx: Context) {
+    let _: Result<(), ResponseError> = ctx.send("limited").await;
+}
+
+async fn build_response(ctx: Context) {
+    let request: Request = ctx.get_request().await;
+    ctx.set_response("hello ").await;
+    ctx.append_response(request).await;
+}
+
+async fn reply_double(ctx: Context) {
+    let value: Arc<u32> = ctx.get_decoded::<u32>().await;
+    let _: Result<(), ResponseError> = ctx.send_encoded(&(*value * 2)).await;
+}
+
 #[tokio::test]
 async fn test_server_with_struct_handler() {
     let server_config: ServerConfig = ServerConfig::new().await;
@@ -10,6 +42,7 @@ async fn test_server_with_struct_handler() {
     server.server_config(server_config).await;
     server.hook::<EchoHandler>().await;
     server.task_panic::<PanicHandler>().await;
+    server.read_error::<ReadErrorHandler>().await;
 }
 
 #[tokio::test]
@@ -28,13 +61,59 @@ async fn test_server_config() {
 async fn test_context_creation() {
     let socket: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
     let socket: ArcRwLockUdpSocket = ArcRwLockUdpSocket::from_socket(socket);
-    let request: Request = vec![1, 2, 3, 4];
+    let request: Request = Request::from(vec![1, 2, 3, 4]);
     let client_addr: std::net::SocketAddr = std::net::SocketAddr::from(([127, 0, 0, 1], 12345));
     let ctx: Context = Context::new(&socket, &request, client_addr);
     assert_eq!(ctx.get_request().await, request);
+    assert_eq!(*ctx.get_request_ref().await, request);
     assert_eq!(ctx.get_client_addr().await, client_addr);
 }
 
+#[tokio::test]
+#[allow(deprecated)]
+async fn test_context_socket_compatibility() {
+    let socket: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let local_addr: SocketAddr = socket.local_addr().unwrap();
+    let lock: ArcRwLock<tokio::net::UdpSocket> = Arc::new(tokio::sync::RwLock::new(socket));
+    let write_guard: tokio::sync::RwLockWriteGuard<'_, tokio::net::UdpSocket> = lock.write().await;
+    assert!(ArcRwLockUdpSocket::try_from(lock.clone()).is_err());
+    drop(write_guard);
+    let socket: ArcRwLockUdpSocket = ArcRwLockUdpSocket::try_from(lock.clone()).unwrap();
+    let read_guard: tokio::sync::RwLockReadGuard<'_, tokio::net::UdpSocket> =
+        socket.get_read_lock().await.unwrap();
+    assert!(lock.try_write().is_err());
+    drop(read_guard);
+    let data: ContextData = ContextData::new(
+        socket.clone(),
+        Request::new(),
+        SocketAddr::from(([127, 0, 0, 1], 1)),
+    );
+    assert_eq!(
+        data.get_udp_socket().unwrap().local_addr().unwrap(),
+        local_addr
+    );
+    let request: Request = Request::from(vec![1, 2, 3, 4]);
+    let peer: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let ctx: Context = Context::new(&socket, &request, peer.local_addr().unwrap());
+    let socket: ArcRwLockUdpSocket = ctx.get_socket().await;
+    assert_eq!(
+        socket.get_read_lock().await.unwrap().local_addr().unwrap(),
+        local_addr
+    );
+    assert_eq!(
+        socket.get_write_lock().await.unwrap().local_addr().unwrap(),
+        local_addr
+    );
+    Response::from("compat")
+        .send(&Some(socket), &Some(peer.local_addr().unwrap()))
+        .await
+        .unwrap();
+    let mut buf: [u8; 16] = [0u8; 16];
+    let (len, from) = peer.recv_from(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"compat");
+    assert_eq!(from, local_addr);
+}
+
 #[tokio::test]
 async fn test_server_run_and_shutdown() {
     #[derive(Clone)]
@@ -65,3 +144,1464 @@ async fn test_server_run_and_shutdown() {
     });
     server_control_hook_1.wait().await;
 }
+
+#[tokio::test]
+async fn test_server_task_panic_hook() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook::<PanickingHandler>().await;
+    server.task_panic::<PanicReplyHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(b"ping", local_addr).await.unwrap();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    let (len, _) = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client.recv_from(&mut buf),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let reply: String = String::from_utf8_lossy(&buf[..len]).into_owned();
+    assert!(reply.contains("handler panicked"));
+    assert!(reply.contains("impl.rs"));
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_panic_releases_slot() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.max_in_flight(1).await;
+    server_config.overload_policy(OverloadPolicy::Block).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook::<PanickingHandler>().await;
+    server.task_panic::<PanickingHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(b"ping", local_addr).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    assert_eq!(server_control_hook.in_flight(), 0);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_graceful_shutdown_drains_read_errors() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.correlation_ids(true).await;
+    server_config
+        .shutdown_timeout(std::time::Duration::from_secs(10))
+        .await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server
+        .read_error_fn(|ctx: Context| async move {
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            let _: Result<(), ResponseError> = ctx.send("drained").await;
+        })
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(b"x", local_addr).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert_eq!(server_control_hook.in_flight(), 0);
+    let started: std::time::Instant = std::time::Instant::now();
+    server_control_hook.graceful_shutdown().await;
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    let (len, _) = tokio::time::timeout(
+        std::time::Duration::from_secs(1),
+        client.recv_from(&mut buf),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(&buf[..len], b"drained");
+}
+
+#[tokio::test]
+async fn test_server_read_error_budget() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.correlation_ids(true).await;
+    server_config.max_in_flight(1).await;
+    server_config.max_read_error_in_flight(1).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server
+        .read_error_fn(|ctx: Context| async move {
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            let _: Result<(), ResponseError> = ctx.send("read error").await;
+        })
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(b"x", local_addr).await.unwrap();
+    client.send_to(b"y", local_addr).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert_eq!(server_control_hook.in_flight(), 0);
+    assert_eq!(server_control_hook.read_errors_dropped(), 1);
+    assert_eq!(server_control_hook.dropped(), 0);
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    let (len, _) = tokio::time::timeout(
+        std::time::Duration::from_secs(1),
+        client.recv_from(&mut buf),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(&buf[..len], b"read error");
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_read_errors_without_hooks() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.correlation_ids(true).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(b"x", local_addr).await.unwrap();
+    client.send_to(b"y", local_addr).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert_eq!(server_control_hook.read_errors_dropped(), 2);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_read_error_context() {
+    let socket: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let socket: ArcRwLockUdpSocket = ArcRwLockUdpSocket::from_socket(socket);
+    let error: RequestError =
+        RequestError::from(std::io::Error::from(std::io::ErrorKind::ConnectionRefused));
+    assert!(matches!(error, RequestError::ConnectionRefused(_)));
+    let ctx: Context = Context::from_read_error(&socket, error.clone(), None);
+    assert_eq!(ctx.get_request_error().await, error);
+    assert_eq!(
+        ctx.try_get_server_error().await,
+        Some(ServerError::UdpRead(error.to_string()))
+    );
+    assert_eq!(ctx.try_get_client_addr().await, None);
+}
+
+#[tokio::test]
+async fn test_server_socket_options() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(60002).await;
+    server_config.ttl(32).await;
+    server_config.reuse_address(true).await;
+    server_config.reuse_port(true).await;
+    server_config.recv_buffer_size(1 << 20).await;
+    server_config.send_buffer_size(1 << 20).await;
+    server_config.dscp(46).await;
+    server_config.broadcast(true).await;
+    let data: ServerConfigData = server_config.get_data().await;
+    assert_eq!(data.get_tos(), Some(46 << 2));
+    let server_1: Server = Server::new().await;
+    server_1.server_config(server_config.clone()).await;
+    let server_2: Server = Server::new().await;
+    server_2.server_config(server_config).await;
+    let server_control_hook_1: ServerControlHook = server_1.run().await.unwrap();
+    let server_control_hook_2: ServerControlHook = server_2.run().await.unwrap();
+    server_control_hook_1.shutdown().await;
+    server_control_hook_2.shutdown().await;
+}
+
+async fn truncation_reply(policy: TruncationPolicy, port: u16) -> String {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(port).await;
+    server_config.buffer_size(8).await;
+    server_config.truncation_policy(policy).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook_fn(reply_truncation).await;
+    server.read_error_fn(reply_request_error).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client
+        .send_to(&[7u8; 32], ("127.0.0.1", port))
+        .await
+        .unwrap();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    let (len, _) = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client.recv_from(&mut buf),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    server_control_hook.shutdown().await;
+    String::from_utf8_lossy(&buf[..len]).into_owned()
+}
+
+#[tokio::test]
+async fn test_server_truncation_policy() {
+    assert_eq!(
+        truncation_reply(TruncationPolicy::Drop, 60003).await,
+        RequestError::BufferTooSmall.to_string()
+    );
+    assert_eq!(
+        truncation_reply(TruncationPolicy::Deliver, 60004).await,
+        "true:8"
+    );
+    assert_eq!(
+        truncation_reply(TruncationPolicy::Grow, 60005).await,
+        "false:32"
+    );
+}
+
+#[tokio::test]
+async fn test_server_multiple_listen_addresses() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.listen("127.0.0.1:0").await;
+    server_config.listen("127.0.0.1:0").await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook_fn(reply_local_addr).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addrs: Vec<SocketAddr> = server_control_hook.get_local_addrs().clone();
+    assert_eq!(local_addrs.len(), 2);
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    for local_addr in local_addrs {
+        client.send_to(b"ping", local_addr).await.unwrap();
+        let mut buf: [u8; 1024] = [0u8; 1024];
+        let (len, from) = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            client.recv_from(&mut buf),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(from, local_addr);
+        assert_eq!(String::from_utf8_lossy(&buf[..len]), local_addr.to_string());
+    }
+    server_control_hook.shutdown().await;
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_server_reply_from_local_addr() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("0.0.0.0").await;
+    server_config.port(0).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook_fn(reply_local_addr).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let port: u16 = server_control_hook.get_local_addrs()[0].port();
+    let target: SocketAddr = SocketAddr::from(([127, 0, 0, 2], port));
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(b"ping", target).await.unwrap();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    let (len, from) = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client.recv_from(&mut buf),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(from, target);
+    assert_eq!(String::from_utf8_lossy(&buf[..len]), target.to_string());
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_graceful_shutdown() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config
+        .shutdown_timeout(std::time::Duration::from_millis(500))
+        .await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook::<SlowEchoHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(b"200", local_addr).await.unwrap();
+    client.send_to(b"10000", local_addr).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert_eq!(server_control_hook.in_flight(), 2);
+    let started: std::time::Instant = std::time::Instant::now();
+    server_control_hook.graceful_shutdown().await;
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    assert_eq!(server_control_hook.in_flight(), 0);
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    let (len, _) = tokio::time::timeout(
+        std::time::Duration::from_secs(1),
+        client.recv_from(&mut buf),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(&buf[..len], b"200");
+    let cancelled: Result<std::io::Result<(usize, SocketAddr)>, tokio::time::error::Elapsed> =
+        tokio::time::timeout(
+            std::time::Duration::from_millis(300),
+            client.recv_from(&mut buf),
+        )
+        .await;
+    assert!(cancelled.is_err());
+    server_control_hook.wait().await;
+}
+
+async fn overload_replies(policy: OverloadPolicy, first: &[u8], second: &[u8]) -> (usize, u64) {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.max_in_flight(1).await;
+    server_config.overload_policy(policy).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook::<SlowEchoHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(first, local_addr).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    client.send_to(second, local_addr).await.unwrap();
+    let mut replies: usize = 0;
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    while tokio::time::timeout(
+        std::time::Duration::from_millis(600),
+        client.recv_from(&mut buf),
+    )
+    .await
+    .is_ok()
+    {
+        replies += 1;
+    }
+    let dropped: u64 = server_control_hook.dropped();
+    server_control_hook.shutdown().await;
+    (replies, dropped)
+}
+
+#[tokio::test]
+async fn test_server_overload_policy() {
+    assert_eq!(
+        overload_replies(OverloadPolicy::DropNewest, b"2000", b"10").await,
+        (0, 1)
+    );
+    assert_eq!(
+        overload_replies(OverloadPolicy::DropOldest, b"2000", b"10").await,
+        (1, 1)
+    );
+    assert_eq!(
+        overload_replies(OverloadPolicy::Block, b"200", b"10").await,
+        (2, 0)
+    );
+}
+
+#[tokio::test]
+async fn test_server_rate_limit() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config
+        .rate_limit(RateLimit::new(0, 2).prefix(24, 64))
+        .await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook::<EchoHandler>().await;
+    server.rate_limited_fn(reply_limited).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    for _ in 0..5 {
+        client.send_to(b"ping", local_addr).await.unwrap();
+    }
+    let mut limited: usize = 0;
+    let mut echoed: usize = 0;
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    while let Ok(Ok((len, _))) = tokio::time::timeout(
+        std::time::Duration::from_millis(500),
+        client.recv_from(&mut buf),
+    )
+    .await
+    {
+        if &buf[..len] == b"limited" {
+            limited += 1;
+        } else {
+            echoed += 1;
+        }
+    }
+    assert_eq!((echoed, limited), (2, 3));
+    assert_eq!(server_control_hook.rate_limited(), 3);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_rate_limited_budget() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config
+        .rate_limit(RateLimit::new(0, 0).max_hooks_in_flight(4))
+        .await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook::<EchoHandler>().await;
+    let started: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+    let finished: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+    let started_hook: Arc<AtomicUsize> = started.clone();
+    let finished_hook: Arc<AtomicUsize> = finished.clone();
+    server
+        .rate_limited_fn(move |_ctx: Context| {
+            let started: Arc<AtomicUsize> = started_hook.clone();
+            let finished: Arc<AtomicUsize> = finished_hook.clone();
+            async move {
+                started.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+                finished.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    for _ in 0..64 {
+        client.send_to(b"ping", local_addr).await.unwrap();
+    }
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert_eq!(server_control_hook.rate_limited(), 64);
+    let started_count: usize = started.load(Ordering::SeqCst);
+    assert!(started_count > 0 && started_count <= 4);
+    assert_eq!(
+        server_control_hook.rate_limited_hooks_dropped(),
+        (64 - started_count) as u64
+    );
+    server_control_hook.graceful_shutdown().await;
+    assert_eq!(finished.load(Ordering::SeqCst), started_count);
+}
+
+#[tokio::test]
+async fn test_server_rate_limit_max_sources() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config
+        .rate_limit(RateLimit::new(0, 1).max_sources(1))
+        .await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook::<EchoHandler>().await;
+    server.rate_limited_fn(reply_limited).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    for limited in [false, true] {
+        let client: tokio::net::UdpSocket =
+            tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        client.send_to(b"ping", local_addr).await.unwrap();
+        let (len, _) = tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            client.recv_from(&mut buf),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(&buf[..len] == b"limited", limited);
+    }
+    assert_eq!(server_control_hook.rate_limited(), 1);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_auto_flush() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.auto_flush(true).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook_fn(build_response).await;
+    server.hook::<EchoHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(b"world", local_addr).await.unwrap();
+    let mut replies: Vec<Vec<u8>> = Vec::new();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    while let Ok(Ok((len, _))) = tokio::time::timeout(
+        std::time::Duration::from_millis(500),
+        client.recv_from(&mut buf),
+    )
+    .await
+    {
+        replies.push(buf[..len].to_vec());
+    }
+    assert_eq!(replies.len(), 1);
+    assert!(replies[0].starts_with(b"Echo: "));
+    server_control_hook.shutdown().await;
+
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.auto_flush(true).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook_fn(build_response).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    client.send_to(b"world", local_addr).await.unwrap();
+    let (len, _) = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client.recv_from(&mut buf),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(&buf[..len], b"hello world");
+    server_control_hook.shutdown().await;
+
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.auto_flush(true).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server
+        .hook_fn(|ctx: Context| async move {
+            ctx.set_response("sent").await;
+            let _: Result<(), ResponseError> = ctx.send_response().await;
+        })
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    client.send_to(b"world", local_addr).await.unwrap();
+    let mut replies: Vec<Vec<u8>> = Vec::new();
+    while let Ok(Ok((len, _))) = tokio::time::timeout(
+        std::time::Duration::from_millis(500),
+        client.recv_from(&mut buf),
+    )
+    .await
+    {
+        replies.push(buf[..len].to_vec());
+    }
+    assert_eq!(replies, vec![b"sent".to_vec()]);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_batching() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.buffer_size(8).await;
+    server_config.batch_size(16).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook_fn(reply_truncation).await;
+    server.read_error_fn(reply_request_error).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    for _ in 0..20 {
+        client.send_to(&[7u8; 4], local_addr).await.unwrap();
+    }
+    client.send_to(&[7u8; 32], local_addr).await.unwrap();
+    let mut replies: Vec<String> = Vec::new();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    while let Ok(Ok((len, from))) = tokio::time::timeout(
+        std::time::Duration::from_millis(500),
+        client.recv_from(&mut buf),
+    )
+    .await
+    {
+        assert_eq!(from, local_addr);
+        replies.push(String::from_utf8_lossy(&buf[..len]).into_owned());
+    }
+    assert_eq!(replies.len(), 21);
+    assert_eq!(
+        replies
+            .iter()
+            .filter(|reply| reply.as_str() == "false:4")
+            .count(),
+        20
+    );
+    assert!(replies.contains(&RequestError::BufferTooSmall.to_string()));
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_segmented_send() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.gro(true).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server
+        .hook_fn(|ctx: Context| async move {
+            let request: Request = ctx.get_request().await;
+            let segment_size: usize = String::from_utf8_lossy(&request).parse().unwrap_or(1);
+            let payload: Vec<u8> = vec![b'x'; segment_size * 3 + segment_size / 2];
+            let _: Result<(), ResponseError> = ctx.send_segmented(payload, segment_size).await;
+        })
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(b"100", local_addr).await.unwrap();
+    let mut sizes: Vec<usize> = Vec::new();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    while let Ok(Ok((len, from))) = tokio::time::timeout(
+        std::time::Duration::from_millis(500),
+        client.recv_from(&mut buf),
+    )
+    .await
+    {
+        assert_eq!(from, local_addr);
+        assert!(buf[..len].iter().all(|byte| *byte == b'x'));
+        sizes.push(len);
+    }
+    assert_eq!(sizes, vec![100, 100, 100, 50]);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_pooled_requests() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.buffer_size(16).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server
+        .hook_fn(|ctx: Context| async move {
+            let request: Request = ctx.get_request().await;
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            let _: Result<(), ResponseError> = ctx.send(request).await;
+        })
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let mut sent: Vec<Vec<u8>> = (0..20u8)
+        .map(|index| vec![index; 5 + index as usize % 7])
+        .collect();
+    for payload in &sent {
+        client.send_to(payload, local_addr).await.unwrap();
+    }
+    let mut replies: Vec<Vec<u8>> = Vec::new();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    while let Ok(Ok((len, _))) = tokio::time::timeout(
+        std::time::Duration::from_millis(500),
+        client.recv_from(&mut buf),
+    )
+    .await
+    {
+        replies.push(buf[..len].to_vec());
+    }
+    sent.sort();
+    replies.sort();
+    assert_eq!(replies, sent);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_workers() {
+    for pin_workers in [false, true] {
+        let server_config: ServerConfig = ServerConfig::new().await;
+        server_config.host("127.0.0.1").await;
+        server_config.port(0).await;
+        server_config.workers(4).await;
+        server_config.pin_workers(pin_workers).await;
+        let server: Server = Server::new().await;
+        server.server_config(server_config).await;
+        server.hook::<EchoHandler>().await;
+        let server_control_hook: ServerControlHook = server.run().await.unwrap();
+        assert_eq!(server_control_hook.get_local_addrs().len(), 1);
+        let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+        let mut clients: Vec<tokio::net::UdpSocket> = Vec::new();
+        for _ in 0..8 {
+            let client: tokio::net::UdpSocket =
+                tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+            client.send_to(b"ping", local_addr).await.unwrap();
+            clients.push(client);
+        }
+        for client in &clients {
+            let mut buf: [u8; 1024] = [0u8; 1024];
+            let (len, from) = tokio::time::timeout(
+                std::time::Duration::from_secs(2),
+                client.recv_from(&mut buf),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+            assert_eq!(from, local_addr);
+            assert!(buf[..len].starts_with(b"Echo: "));
+        }
+        server_control_hook.graceful_shutdown().await;
+    }
+}
+
+#[tokio::test]
+async fn test_client() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook_fn(reply_truncation).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    client_config
+        .timeout(std::time::Duration::from_millis(200))
+        .await;
+    client_config.retries(1).await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    assert_eq!(
+        client.send("ping").await,
+        Err(ResponseError::AddressNotAvailable)
+    );
+    let reply: Request = client.request_to("ping", local_addr).await.unwrap();
+    assert_eq!(&reply[..], b"false:4");
+    client.connect(local_addr).await.unwrap();
+    assert_eq!(client.get_peer_addr().await, Some(local_addr));
+    let reply: Request = client.request("hello").await.unwrap();
+    assert_eq!(&reply[..], b"false:5");
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+    let silent: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let silent_addr: SocketAddr = silent.local_addr().unwrap();
+    let started: std::time::Instant = std::time::Instant::now();
+    assert_eq!(
+        client.request_to("ping", silent_addr).await,
+        Err(ResponseError::Timeout)
+    );
+    assert!(started.elapsed() >= std::time::Duration::from_millis(400));
+    let mut buf: [u8; 16] = [0u8; 16];
+    let (len, _) = silent.recv_from(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"ping");
+}
+
+#[tokio::test]
+#[cfg(target_os = "linux")]
+async fn test_client_receive_error_fails_requests() {
+    let closed: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let closed_addr: SocketAddr = closed.local_addr().unwrap();
+    drop(closed);
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    client_config.correlation_ids(true).await;
+    client_config
+        .timeout(std::time::Duration::from_secs(5))
+        .await;
+    client_config.retries(0).await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    client.connect(closed_addr).await.unwrap();
+    let started: std::time::Instant = std::time::Instant::now();
+    assert!(matches!(
+        client.request("ping").await,
+        Err(ResponseError::ReceiveError(_))
+    ));
+    assert!(started.elapsed() < std::time::Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_server_correlation_ids() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config.correlation_ids(true).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.hook::<SlowEchoHandler>().await;
+    server.read_error_fn(reply_request_error).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    client_config.correlation_ids(true).await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    client.connect(local_addr).await.unwrap();
+    let requests: Vec<String> = ["250", "200", "150", "100", "50"]
+        .iter()
+        .map(|delay| delay.to_string())
+        .collect();
+    let handles: Vec<tokio::task::JoinHandle<Result<Request, ResponseError>>> = requests
+        .iter()
+        .map(|request| {
+            let client: Client = client.clone();
+            let request: String = request.clone();
+            tokio::spawn(async move { client.request(request).await })
+        })
+        .collect();
+    for (request, handle) in requests.iter().zip(handles) {
+        let reply: Request = handle.await.unwrap().unwrap();
+        assert_eq!(&reply[..], request.as_bytes());
+    }
+    let raw: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    raw.send_to(&[1, 2], local_addr).await.unwrap();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    let (len, _) = raw.recv_from(&mut buf).await.unwrap();
+    assert_eq!(
+        &buf[..len],
+        RequestError::MissingCorrelationId.to_string().as_bytes()
+    );
+    raw.send_to(&[0, 0, 0, 9, b'5'], local_addr).await.unwrap();
+    let (len, _) = raw.recv_from(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], &[0, 0, 0, 9, b'5']);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_router() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.route_opcode::<RouteReplyHandler<'o'>>(0x01).await;
+    server.route_prefix::<RouteReplyHandler<'p'>>("MAGIC").await;
+    server
+        .route_prefix::<RouteReplyHandler<'l'>>("MAGIC2")
+        .await;
+    server
+        .route_key_extractor(|data: &[u8]| {
+            let text: &str = std::str::from_utf8(data).ok()?;
+            text.split_once(':').map(|(key, _)| key.as_bytes().to_vec())
+        })
+        .await;
+    server.route_key::<RouteReplyHandler<'k'>>("user").await;
+    server.fallback::<RouteReplyHandler<'f'>>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    client.connect(local_addr).await.unwrap();
+    for (request, route) in [
+        (&b"\x01payload"[..], "o"),
+        (b"MAGIC payload", "p"),
+        (b"MAGIC2 payload", "l"),
+        (b"user:42", "k"),
+        (b"group:42", "f"),
+        (b"", "f"),
+    ] {
+        let reply: Request = client.request(request.to_vec()).await.unwrap();
+        assert_eq!(&reply[..], route.as_bytes());
+    }
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_codec() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.codec(U32Codec).await;
+    server.hook_fn(reply_double).await;
+    server.decode_error_fn(reply_request_error).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    client.connect(local_addr).await.unwrap();
+    let reply: Request = client.request(21u32.to_be_bytes().to_vec()).await.unwrap();
+    assert_eq!(&reply[..], &42u32.to_be_bytes());
+    let reply: Request = client.request(vec![1, 2, 3]).await.unwrap();
+    assert_eq!(
+        &reply[..],
+        RequestError::DecodeError("expected 4 bytes, got 3".to_owned())
+            .to_string()
+            .as_bytes()
+    );
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_decode_error_guarded() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let fallback_server: Server = Server::new().await;
+    fallback_server.server_config(server_config.clone()).await;
+    fallback_server.codec(U32Codec).await;
+    fallback_server.hook_fn(reply_double).await;
+    fallback_server
+        .read_error_fn(|ctx: Context| async move {
+            let error: RequestError = ctx.get_request_error().await;
+            let _: Result<(), ResponseError> = ctx.send(format!("read_error:{error}")).await;
+        })
+        .await;
+    let panic_server: Server = Server::new().await;
+    panic_server.server_config(server_config).await;
+    panic_server.codec(U32Codec).await;
+    panic_server.hook_fn(reply_double).await;
+    panic_server
+        .decode_error_fn(|_ctx: Context| async move {
+            panic!("decode hook panicked");
+        })
+        .await;
+    panic_server.task_panic::<PanicReplyHandler>().await;
+    let expected: [&str; 2] = [
+        "read_error:Decode error: expected 4 bytes, got 3",
+        "decode hook panicked",
+    ];
+    for (server, expected) in [fallback_server, panic_server].iter().zip(expected) {
+        let server_control_hook: ServerControlHook = server.run().await.unwrap();
+        let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+        let client_config: ClientConfig = ClientConfig::new().await;
+        client_config.bind("127.0.0.1:0").await;
+        let client: Client = Client::new(client_config).await.unwrap();
+        client.connect(local_addr).await.unwrap();
+        let reply: Request = client.request(vec![1, 2, 3]).await.unwrap();
+        assert!(String::from_utf8_lossy(&reply).contains(expected));
+        server_control_hook.shutdown().await;
+    }
+}
+
+#[cfg(feature = "json")]
+#[tokio::test]
+async fn test_server_json() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.route_opcode::<SwapJsonHandler>(b'[').await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    client.connect(local_addr).await.unwrap();
+    let reply: Request = client.request(br#"["answer",42]"#.to_vec()).await.unwrap();
+    assert_eq!(&reply[..], br#"[42,"answer"]"#);
+    let reply: Request = client.request(br#"[42]"#.to_vec()).await.unwrap();
+    assert!(String::from_utf8_lossy(&reply).starts_with("Decode error: "));
+    server_control_hook.shutdown().await;
+}
+
+#[cfg(all(feature = "json", feature = "cbor", feature = "msgpack"))]
+#[tokio::test]
+async fn test_server_serde_codecs() {
+    let request: (String, u32) = ("answer".to_owned(), 42);
+    let response: (u32, String) = (42, "answer".to_owned());
+    let json_server: Server = Server::new().await;
+    json_server
+        .codec(JsonCodec::<(String, u32), (u32, String)>::new())
+        .await;
+    let cbor_server: Server = Server::new().await;
+    cbor_server
+        .codec(CborCodec::<(String, u32), (u32, String)>::new())
+        .await;
+    let msgpack_server: Server = Server::new().await;
+    msgpack_server
+        .codec(MsgPackCodec::<(String, u32), (u32, String)>::new())
+        .await;
+    let mut cbor_request: ResponseData = Vec::new();
+    ciborium::into_writer(&request, &mut cbor_request).unwrap();
+    let mut cbor_response: ResponseData = Vec::new();
+    ciborium::into_writer(&response, &mut cbor_response).unwrap();
+    let codecs: Vec<(Server, ResponseData, ResponseData)> = vec![
+        (
+            json_server,
+            serde_json::to_vec(&request).unwrap(),
+            serde_json::to_vec(&response).unwrap(),
+        ),
+        (cbor_server, cbor_request, cbor_response),
+        (
+            msgpack_server,
+            rmp_serde::to_vec_named(&request).unwrap(),
+            rmp_serde::to_vec_named(&response).unwrap(),
+        ),
+    ];
+    for (server, request, response) in codecs {
+        let server_config: ServerConfig = ServerConfig::new().await;
+        server_config.host("127.0.0.1").await;
+        server_config.port(0).await;
+        server.server_config(server_config).await;
+        server
+            .hook_fn(|ctx: Context| async move {
+                let request: Arc<(String, u32)> = ctx.get_decoded::<(String, u32)>().await;
+                let response: (u32, String) = (request.1, request.0.clone());
+                let _: Result<(), ResponseError> = ctx.send_encoded(&response).await;
+            })
+            .await;
+        server.decode_error_fn(reply_request_error).await;
+        let server_control_hook: ServerControlHook = server.run().await.unwrap();
+        let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+        let client_config: ClientConfig = ClientConfig::new().await;
+        client_config.bind("127.0.0.1:0").await;
+        let client: Client = Client::new(client_config).await.unwrap();
+        client.connect(local_addr).await.unwrap();
+        let reply: Request = client.request(request).await.unwrap();
+        assert_eq!(&reply[..], &response[..]);
+        let reply: Request = client.request(vec![0xff]).await.unwrap();
+        assert!(String::from_utf8_lossy(&reply).starts_with("Decode error: "));
+        server_control_hook.shutdown().await;
+    }
+}
+
+#[tokio::test]
+async fn test_server_hook_fn() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server
+        .hook_fn(|ctx: Context| async move {
+            ctx.set_attribute("prefix", "echo:".to_owned()).await;
+        })
+        .await;
+    server
+        .hook_fn(|ctx: Context| async move {
+            let request: Request = ctx.get_request().await;
+            if &request[..] == b"panic" {
+                panic!("closure panicked");
+            }
+            let prefix: String = ctx.get_attribute("prefix").await;
+            let mut reply: Vec<u8> = prefix.into_bytes();
+            reply.extend_from_slice(&request);
+            let _: Result<(), ResponseError> = ctx.send(reply).await;
+        })
+        .await;
+    server
+        .task_panic_fn(|ctx: Context| async move {
+            let message: String = ctx.get_panic_data().await.to_string();
+            let _: Result<(), ResponseError> = ctx.send(message).await;
+        })
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    client.connect(local_addr).await.unwrap();
+    let reply: Request = client.request(b"ping".to_vec()).await.unwrap();
+    assert_eq!(&reply[..], b"echo:ping");
+    let reply: Request = client.request(b"panic".to_vec()).await.unwrap();
+    assert!(String::from_utf8_lossy(&reply).contains("closure panicked"));
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_state() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let hits: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.state(hits.clone()).await;
+    server.state("first").await;
+    server.state("counter").await;
+    server.hook::<CounterHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    client.connect(local_addr).await.unwrap();
+    for expected in ["counter:1", "counter:2"] {
+        let reply: Request = client.request(b"hit".to_vec()).await.unwrap();
+        assert_eq!(&reply[..], expected.as_bytes());
+    }
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+    let ctx: Context = Context::default();
+    assert!(ctx.try_get_state::<Arc<AtomicUsize>>().await.is_none());
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_middleware() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.middleware::<RunCountMiddleware>().await;
+    server
+        .middleware_fn(|ctx: Context, next: Next| async move {
+            let request: Request = ctx.get_request().await;
+            next.run(&ctx).await;
+            if &request[..] == b"retry" {
+                next.run(&ctx).await;
+            }
+        })
+        .await;
+    server.hook::<RunCountHandler>().await;
+    server.route_prefix::<RunCountHandler>("retry").await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    client.connect(local_addr).await.unwrap();
+    for (request, expected) in [(&b"once"[..], "runs:1"), (b"retry", "runs:4")] {
+        let reply: Request = client.request(request.to_vec()).await.unwrap();
+        assert_eq!(&reply[..], expected.as_bytes());
+    }
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_middleware_retry() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server
+        .middleware_fn(|ctx: Context, next: Next| async move {
+            ctx.set_attribute("attempt", 0usize).await;
+            let mut outcomes: Vec<&str> = Vec::new();
+            loop {
+                let outcome: NextOutcome = next.run(&ctx).await;
+                outcomes.push(match outcome {
+                    NextOutcome::Completed => "completed",
+                    NextOutcome::Aborted => "aborted",
+                    NextOutcome::Panicked(_) => "panicked",
+                    NextOutcome::Error(_) => "error",
+                });
+                if matches!(outcome, NextOutcome::Completed) {
+                    break;
+                }
+            }
+            let runs: usize = ctx.get_attribute("runs").await;
+            let _: Result<(), ResponseError> =
+                ctx.send(format!("{}:{runs}", outcomes.join(","))).await;
+        })
+        .await;
+    server
+        .hook_fn(|ctx: Context| async move {
+            let attempt: usize = ctx.get_attribute("attempt").await;
+            ctx.set_attribute("attempt", attempt + 1).await;
+            ctx.set_attribute("runs", 0usize).await;
+            match attempt {
+                0 => ctx.aborted().await,
+                1 => panic!("flaky handler"),
+                _ => {}
+            }
+        })
+        .await;
+    server.hook::<RunCountHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    client.connect(local_addr).await.unwrap();
+    let reply: Request = client.request(b"retry".to_vec()).await.unwrap();
+    assert_eq!(&reply[..], b"aborted,panicked,completed:1");
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_request_timeout() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config
+        .request_timeout(std::time::Duration::from_millis(200))
+        .await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server.route_prefix::<SlowEchoHandler>("5").await;
+    server.fallback::<SlowEchoHandler>().await;
+    server
+        .route_prefix_timeout("5", std::time::Duration::from_secs(2))
+        .await
+        .unwrap();
+    assert_eq!(
+        server
+            .route_prefix_timeout("7", std::time::Duration::from_secs(2))
+            .await
+            .err(),
+        Some(ServerError::UnknownRoute("prefix [55]".to_owned()))
+    );
+    server
+        .request_timeout_fn(|ctx: Context| async move {
+            assert!(ctx.get_aborted().await);
+            let error: RequestError = ctx.get_request_error().await;
+            let _: Result<(), ResponseError> = ctx.send(error.to_string()).await;
+        })
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client_config: ClientConfig = ClientConfig::new().await;
+    client_config.bind("127.0.0.1:0").await;
+    let client: Client = Client::new(client_config).await.unwrap();
+    client.connect(local_addr).await.unwrap();
+    for (request, expected) in [
+        ("0", "0"),
+        ("500", "500"),
+        ("1000", "Request timed out after 200ms"),
+    ] {
+        let reply: Request = client.request(request.as_bytes().to_vec()).await.unwrap();
+        assert_eq!(&reply[..], expected.as_bytes());
+    }
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_hook_timeout() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server
+        .hook_fn_with_timeout(
+            std::time::Duration::from_millis(100),
+            |_ctx: Context| async move {
+                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            },
+        )
+        .await;
+    server.hook::<EchoHandler>().await;
+    server
+        .request_timeout_fn(|ctx: Context| async move {
+            let error: RequestError = ctx.get_request_error().await;
+            let _: Result<(), ResponseError> = ctx.send(error.to_string()).await;
+            panic!("timeout hook panicked");
+        })
+        .await;
+    server.task_panic::<PanicReplyHandler>().await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    client.send_to(b"ping", local_addr).await.unwrap();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    for expected in ["Request timed out after 100ms", "timeout hook panicked"] {
+        let (len, _) = tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            client.recv_from(&mut buf),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert!(String::from_utf8_lossy(&buf[..len]).contains(expected));
+    }
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert_eq!(server_control_hook.in_flight(), 0);
+    server_control_hook.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_server_sessions() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config
+        .session_idle_timeout(std::time::Duration::from_millis(300))
+        .await;
+    server_config.max_sessions(1).await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server
+        .hook_fn(|ctx: Context| async move {
+            let Some(session) = ctx.session().await else {
+                let _: Result<(), ResponseError> = ctx.send("full").await;
+                return;
+            };
+            let count: usize = session.get_attribute::<usize, _>("count").await + 1;
+            session.set_attribute("count", count).await;
+            let _: Result<(), ResponseError> = ctx.send(count.to_string()).await;
+        })
+        .await;
+    server
+        .session_created_fn(|ctx: Context| async move {
+            ctx.get_session().await.set_attribute("count", 0usize).await;
+        })
+        .await;
+    server
+        .session_expired_fn(|ctx: Context| async move {
+            let count: usize = ctx.get_session().await.get_attribute("count").await;
+            let _: Result<(), ResponseError> = ctx.send(format!("expired:{count}")).await;
+        })
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let first: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let second: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    for (socket, expected) in [(&first, "1"), (&first, "2"), (&second, "full")] {
+        socket.send_to(b"hit", local_addr).await.unwrap();
+        let (len, _) = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            socket.recv_from(&mut buf),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(&buf[..len], expected.as_bytes());
+    }
+    assert_eq!(server_control_hook.sessions(), 1);
+    let (len, _) =
+        tokio::time::timeout(std::time::Duration::from_secs(5), first.recv_from(&mut buf))
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(&buf[..len], b"expired:2");
+    assert_eq!(server_control_hook.sessions(), 0);
+    second.send_to(b"hit", local_addr).await.unwrap();
+    let (len, _) = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        second.recv_from(&mut buf),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(&buf[..len], b"1");
+    server_control_hook.shutdown().await;
+    server_control_hook.wait().await;
+    assert_eq!(server_control_hook.sessions(), 0);
+}
+
+#[tokio::test]
+async fn test_server_session_hooks_isolated() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config
+        .session_idle_timeout(std::time::Duration::from_millis(100))
+        .await;
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    server
+        .hook_fn(|ctx: Context| async move {
+            let reply: &str = match ctx.session().await {
+                Some(_) => "session",
+                None => "none",
+            };
+            let _: Result<(), ResponseError> = ctx.send(reply).await;
+        })
+        .await;
+    server
+        .session_created_fn(|ctx: Context| async move {
+            ctx.aborted().await;
+        })
+        .await;
+    let expired: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+    let expired_count: Arc<AtomicUsize> = expired.clone();
+    server
+        .session_expired_fn(move |_ctx: Context| {
+            let expired_count: Arc<AtomicUsize> = expired_count.clone();
+            async move {
+                expired_count.fetch_add(1, Ordering::SeqCst);
+                panic!("session expired hook panic");
+            }
+        })
+        .await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+    let client: tokio::net::UdpSocket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let mut buf: [u8; 1024] = [0u8; 1024];
+    for round in 1..=2 {
+        client.send_to(b"hit", local_addr).await.unwrap();
+        let (len, _) = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            client.recv_from(&mut buf),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(&buf[..len], b"session");
+        tokio::time::sleep(std::time::Duration::from_millis(400)).await;
+        assert_eq!(server_control_hook.sessions(), 0);
+        assert_eq!(expired.load(Ordering::SeqCst), round);
+    }
+    server_control_hook.graceful_shutdown().await;
+}
+
+#[test]
+fn test_server_spawn_during_runtime_shutdown() {
+    let (done_sender, done_receiver) = std::sync::mpsc::channel::<()>();
+    std::thread::spawn(move || {
+        let runtime: tokio::runtime::Runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let server_config: ServerConfig = ServerConfig::new().await;
+            server_config.host("127.0.0.1").await;
+            server_config.port(0).await;
+            server_config
+                .session_idle_timeout(std::time::Duration::from_secs(1))
+                .await;
+            server_config.max_sessions(1).await;
+            let server: Server = Server::new().await;
+            server.server_config(server_config).await;
+            server
+                .hook_fn(|ctx: Context| async move {
+                    if ctx.get_request().await.as_ref() == b"session" {
+                        let _: Option<Session> = ctx.try_get_session().await;
+                    } else {
+                        let probe: SessionProbe = SessionProbe(ctx.clone());
+                        tokio::spawn(async move {
+                            let _probe: SessionProbe = probe;
+                            std::future::pending::<()>().await;
+                        });
+                    }
+                    let _: Result<(), ResponseError> = ctx.send("ok").await;
+                })
+                .await;
+            server.session_expired_fn(|_ctx: Context| async {}).await;
+            let server_control_hook: ServerControlHook = server.run().await.unwrap();
+            let local_addr: SocketAddr = server_control_hook.get_local_addrs()[0];
+            tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+            let mut buf: [u8; 16] = [0u8; 16];
+            for request in [b"session".as_slice(), b"probe".as_slice()] {
+                let client: tokio::net::UdpSocket =
+                    tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+                client.send_to(request, local_addr).await.unwrap();
+                client.recv_from(&mut buf).await.unwrap();
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(1150)).await;
+        });
+        drop(runtime);
+        let _: Result<(), std::sync::mpsc::SendError<()>> = done_sender.send(());
+    });
+    assert!(
+        done_receiver
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .is_ok()
+    );
+}
+
+#[tokio::test]
+async fn test_server_sessions_zero_idle_timeout() {
+    let server_config: ServerConfig = ServerConfig::new().await;
+    server_config.host("127.0.0.1").await;
+    server_config.port(0).await;
+    server_config
+        .session_idle_timeout(std::time::Duration::ZERO)
+        .await;
+    assert_eq!(
+        server_config.get_data().await.get_session_idle_timeout(),
+        MIN_SESSION_IDLE_TIMEOUT
+    );
+    let server: Server = Server::new().await;
+    server.server_config(server_config).await;
+    let server_control_hook: ServerControlHook = server.run().await.unwrap();
+    server_control_hook.shutdown().await;
+}
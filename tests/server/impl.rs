@@ -21,3 +21,142 @@ impl ServerHook for PanicHandler {
         println!("A task panic occurred");
     }
 }
+
+impl ServerHook for PanickingHandler {
+    async fn new(_ctx: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, _ctx: &Context) {
+        panic!("handler panicked");
+    }
+}
+
+impl ServerHook for PanicReplyHandler {
+    async fn new(_ctx: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let panic_data: PanicData = ctx.get_panic_data().await;
+        let _: Result<(), ResponseError> = ctx.send(panic_data.to_string()).await;
+    }
+}
+
+impl ServerHook for ReadErrorHandler {
+    async fn new(_ctx: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        println!(
+            "A read error occurred: {:?}",
+            ctx.try_get_request_error().await
+        );
+    }
+}
+
+impl ServerHook for SlowEchoHandler {
+    async fn new(_ctx: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let request: Request = ctx.get_request().await;
+        let millis: u64 = String::from_utf8_lossy(&request).parse().unwrap_or(0);
+        tokio::time::sleep(std::time::Duration::from_millis(millis)).await;
+        let _: Result<(), ResponseError> = ctx.send(request).await;
+    }
+}
+
+impl<const ROUTE: char> ServerHook for RouteReplyHandler<ROUTE> {
+    async fn new(_ctx: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let _: Result<(), ResponseError> = ctx.send(ROUTE.to_string()).await;
+    }
+}
+
+impl Codec for U32Codec {
+    type Request = u32;
+    type Response = u32;
+    type Error = String;
+
+    fn decode(&self, data: &[u8]) -> Result<u32, String> {
+        let bytes: [u8; 4] = data
+            .try_into()
+            .map_err(|_| format!("expected 4 bytes, got {}", data.len()))?;
+        Ok(u32::from_be_bytes(bytes))
+    }
+
+    fn encode(&self, response: &u32) -> Result<ResponseData, String> {
+        Ok(response.to_be_bytes().to_vec())
+    }
+}
+
+#[cfg(feature = "json")]
+impl ServerHook for SwapJsonHandler {
+    async fn new(_ctx: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        match ctx.get_json::<(String, u32)>().await {
+            Ok((name, value)) => {
+                let _: Result<(), ResponseError> = ctx.send_json(&(value, name)).await;
+            }
+            Err(error) => {
+                let _: Result<(), ResponseError> = ctx.send(error.to_string()).await;
+            }
+        }
+    }
+}
+
+impl ServerHook for CounterHandler {
+    async fn new(ctx: &Context) -> Self {
+        Self {
+            hits: ctx.get_state::<Arc<AtomicUsize>>().await,
+        }
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let hits: usize = self.hits.fetch_add(1, Ordering::SeqCst) + 1;
+        let name: &'static str = ctx.get_state::<&'static str>().await;
+        let _: Result<(), ResponseError> = ctx.send(format!("{name}:{hits}")).await;
+    }
+}
+
+impl ServerMiddleware for RunCountMiddleware {
+    async fn new(_ctx: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context, next: Next) {
+        ctx.set_attribute("runs", 0usize).await;
+        next.run(ctx).await;
+        let runs: usize = ctx.get_attribute("runs").await;
+        let _: Result<(), ResponseError> = ctx.send(format!("runs:{runs}")).await;
+    }
+}
+
+impl ServerHook for RunCountHandler {
+    async fn new(_ctx: &Context) -> Self {
+        Self
+    }
+
+    async fn handle(self, ctx: &Context) {
+        let runs: usize = ctx.get_attribute("runs").await;
+        ctx.set_attribute("runs", runs + 1).await;
+    }
+}
+
+impl Drop for SessionProbe {
+    fn drop(&mut self) {
+        let mut session = std::pin::pin!(self.0.try_get_session());
+        let _: std::task::Poll<Option<Session>> = session
+            .as_mut()
+            .poll(&mut std::task::Context::from_waker(std::task::Waker::noop()));
+    }
+}
@@ -1,5 +1,42 @@
+use super::*;
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct EchoHandler;
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct PanicHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PanickingHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PanicReplyHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ReadErrorHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SlowEchoHandler;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RouteReplyHandler<const ROUTE: char>;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct U32Codec;
+
+#[cfg(feature = "json")]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SwapJsonHandler;
+
+#[derive(Clone, Debug)]
+pub(crate) struct CounterHandler {
+    pub(crate) hits: Arc<AtomicUsize>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RunCountMiddleware;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RunCountHandler;
+
+pub(crate) struct SessionProbe(pub(crate) Context);